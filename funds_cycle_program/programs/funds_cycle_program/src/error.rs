@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+// Anchor error codes are positional: new variants are appended at the
+// end and retired ones stay in place, so existing codes never shift.
 #[error_code]
 pub enum FundCycleError {
     // ========= beneficiary error =====
@@ -25,6 +27,7 @@ pub enum FundCycleError {
     MathOverflow,
     #[msg("No funds available for withdrawal")]
     NoFundsAvailable,
+    /// Deprecated: the vault ledger reports which bucket is short instead
     #[msg("Insufficient balance in vault")]
     InsufficientVaultBalance,
 
@@ -43,6 +46,7 @@ pub enum FundCycleError {
     MissingBeneficiaryAccount,
     #[msg("Collateral already claimed")]
     AlreadyClaimed,
+    /// Deprecated: the vault ledger reports which bucket is short instead
     #[msg("Insufficient funds in vault")]
     InsufficientVaultFunds,
 
@@ -55,4 +59,190 @@ pub enum FundCycleError {
     NotAllClaimed,
     #[msg("Invalid config account")]
     InvalidConfig,
-}
\ No newline at end of file
+
+    // ========= invite error =====
+    #[msg("Invite has expired")]
+    InviteExpired,
+    #[msg("Invite was issued for a different slot")]
+    InviteSlotMismatch,
+    #[msg("Missing or invalid admin signature on invite")]
+    InvalidInviteSignature,
+
+    // ========= enrollment error =====
+    #[msg("Enrollment is closed for this cycle")]
+    EnrollmentClosed,
+    #[msg("Enrollment deadline has not passed yet")]
+    EnrollmentStillOpen,
+    #[msg("Not enough participants to start the cycle")]
+    UnderSubscribed,
+    #[msg("Cycle has enough participants, cannot cancel")]
+    NotUnderSubscribed,
+    #[msg("Invalid enrollment terms")]
+    InvalidEnrollmentTerms,
+    #[msg("Cycle is not active")]
+    CycleNotActive,
+    #[msg("Cycle has been cancelled")]
+    CycleCancelled,
+    #[msg("Cycle has not been cancelled")]
+    CycleNotCancelled,
+    #[msg("All rounds have already been paid out")]
+    CycleAlreadyComplete,
+
+    // ========= remove_beneficiary error =====
+    #[msg("Rent payer does not match beneficiary account")]
+    InvalidRentPayer,
+    #[msg("Account passed is not the beneficiary in the last slot")]
+    LastSlotMismatch,
+
+    // ========= early_exit error =====
+    #[msg("Beneficiary has already received a payout")]
+    AlreadyReceivedPayout,
+    #[msg("Invalid early exit terms")]
+    InvalidExitTerms,
+
+    // ========= fill_vacancy error =====
+    #[msg("Slot is not vacant")]
+    SlotNotVacant,
+    #[msg("Vacancy has already been filled")]
+    VacancyAlreadyFilled,
+
+    // ========= advance_round error =====
+    #[msg("Payout window is still open for the current recipient")]
+    PayoutWindowOpen,
+
+    // ========= admin error =====
+    #[msg("No admin handover pending for this signer")]
+    NoPendingAdmin,
+
+    // ========= committee error =====
+    #[msg("Invalid committee members or threshold")]
+    InvalidCommittee,
+    #[msg("Signer is not a committee member")]
+    NotCommitteeMember,
+    #[msg("Member has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal has not reached the approval threshold")]
+    ThresholdNotMet,
+    #[msg("Accounts passed do not match the approved proposal")]
+    ProposalAccountMismatch,
+
+    // ========= vote punish error =====
+    #[msg("Punishment must go through a member vote")]
+    PunishVoteRequired,
+    #[msg("Cycle is not in vote punish mode")]
+    PunishVoteNotEnabled,
+    #[msg("Invalid punishment terms")]
+    InvalidPunishTerms,
+    #[msg("Voting window has closed")]
+    VoteWindowClosed,
+    #[msg("Voting window is still open")]
+    VoteWindowOpen,
+    #[msg("The accused member cannot vote on their own punishment")]
+    AccusedCannotVote,
+
+    // ========= strike error =====
+    #[msg("Member already has a strike for this round")]
+    StrikeAlreadyIssued,
+
+    // ========= pause error =====
+    #[msg("Cycle is paused")]
+    CyclePaused,
+    #[msg("Cycle is not paused")]
+    CycleNotPaused,
+
+    // ========= dissolution error =====
+    #[msg("Cycle is not being dissolved")]
+    NotDissolving,
+    #[msg("Every active beneficiary account must be passed exactly once")]
+    IncompleteBeneficiarySet,
+
+    // ========= close_beneficiary error =====
+    #[msg("Collateral has not been claimed yet")]
+    CollateralNotClaimed,
+    #[msg("Beneficiary accounts must be passed with their rent payers")]
+    InvalidSweepAccounts,
+
+    // ========= vault ledger error =====
+    #[msg("Vault holds too little collateral for this transfer")]
+    InsufficientCollateralHeld,
+    #[msg("Contribution pool is too small for this payout")]
+    InsufficientContributionPool,
+    #[msg("Reserve is too small for this transfer")]
+    InsufficientReserve,
+    #[msg("Forfeited balance is too small for this transfer")]
+    InsufficientForfeited,
+    #[msg("Vault cannot cover outstanding collateral and reserve")]
+    VaultInsolvent,
+    #[msg("Transfer would leave the vault below its rent-exempt minimum")]
+    VaultBelowRentExempt,
+
+    // ========= reserve yield error =====
+    #[msg("No yield program is configured")]
+    YieldProgramNotSet,
+    #[msg("Account is not the configured yield program")]
+    InvalidYieldProgram,
+    #[msg("Reserve must be recalled from the yield program or stake account first")]
+    ReserveDeployed,
+    #[msg("Invalid reserve amount")]
+    InvalidYieldAmount,
+    #[msg("Reserve is already staked")]
+    ReserveAlreadyStaked,
+    #[msg("No reserve is staked")]
+    NoReserveStaked,
+    #[msg("Final round has started, reserve must stay liquid")]
+    FinalRoundStarted,
+
+    // ========= savings club error =====
+    #[msg("Savings-club cycles have no payout turns")]
+    WithdrawDisabled,
+
+    // ========= payout split error =====
+    #[msg("Payouts per round must be between 1 and the number of slots")]
+    InvalidPayoutSplit,
+
+    // ========= vesting error =====
+    #[msg("Payouts vest, a stream account must be passed")]
+    MissingStreamAccount,
+    #[msg("Vesting balance is too small for this transfer")]
+    InsufficientVesting,
+    #[msg("Nothing has vested since the last claim")]
+    NothingVested,
+    #[msg("Payout streams have not been fully claimed")]
+    StreamsOutstanding,
+
+    // ========= payout settings error =====
+    #[msg("Account does not match the member's payout destination")]
+    InvalidPayoutDestination,
+    #[msg("Nominee cannot be the member's own wallet")]
+    InvalidNominee,
+    #[msg("Signer is not the member, or the member is not dormant yet")]
+    NomineeNotEligible,
+
+    // ========= committee rotation error =====
+    #[msg("Committee membership changed since this proposal was filed")]
+    StaleProposal,
+
+    // ========= fill_vacancy turn error =====
+    #[msg("Vacated slot's payout turn has already passed")]
+    VacancyTurnPassed,
+
+    // ========= vesting stream error =====
+    #[msg("Payouts do not vest, no stream account may be passed")]
+    UnexpectedStreamAccount,
+
+    // ========= vote deadline error =====
+    #[msg("A voting window must be set for this vote")]
+    VoteWindowNotSet,
+    #[msg("Vote records do not match the proposal's votes")]
+    IncompleteVoteRecords,
+
+    // ========= early exit guard error =====
+    #[msg("A dissolution vote is open")]
+    DissolutionPending,
+
+    // ========= fee ledger error =====
+    #[msg("Fees owed are too small for this transfer")]
+    InsufficientFeesOwed,
+}
@@ -4,23 +4,29 @@ use crate::{ error::FundCycleError, state::* };
 
 #[derive(Accounts)]
 pub struct AddBeneficiary<'info> {
-    #[account(mut)]
     pub admin: Signer<'info>,
 
     #[account(
         mut,
         has_one = admin,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Enrollment @ FundCycleError::EnrollmentClosed,
         constraint = config.current_index < config.max_beneficiaries @ FundCycleError::MaxBeneficiariesReached
     )]
     pub config: Account<'info, ConfigAccount>,
 
+    /// Pays the rent - kept apart from the admin, which is an unfunded
+    /// PDA when a committee runs the cycle
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     /// This is the wallet we are adding as beneficiary
     /// Can be the same as `admin` or different — usually a different user.
     pub wallet: SystemAccount<'info>,
 
     #[account(
         init,
-        payer = admin,
+        payer = payer,
         seeds = [b"beneficiary", config.key().as_ref(), wallet.key().as_ref()],
         bump,
         space = 8 + BeneficiaryAccount::INIT_SPACE
@@ -44,12 +50,34 @@ impl<'info> AddBeneficiary<'info> {
             bump: bumps.beneficiary,
             last_payment_ts: 0, 
             active: true,
-            collateral_claimed:false
+            collateral_claimed:false,
+            payer: self.payer.key(),
+            contributions: 0,
+            payout_received: false,
+            exited: false,
+            replaced: false,
+            skipped: false,
+            paid_round: 0,
+            strikes: 0,
+            last_strike_round: None,
+            on_time_streak: 0,
+            fines_paid: 0,
+            paused_secs_at_payment: self.config.paused_secs,
+            payouts_received: 0,
+            payout_destination: None,
+            nominee: None,
+            last_active_ts: 0,
+            paused_secs_at_active: 0,
         });
 
         // Increase count in config
         self.config.current_index += 1;
 
+        // Last slot taken - the round robin starts
+        if self.config.current_index == self.config.max_beneficiaries {
+            self.config.start_cycle(Clock::get()?.unix_timestamp);
+        }
+
         Ok(())
     }
 }
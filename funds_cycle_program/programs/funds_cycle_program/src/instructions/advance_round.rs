@@ -0,0 +1,143 @@
+// instructions/advance_round.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::FundCycleError;
+
+#[derive(Accounts)]
+pub struct AdvanceRound<'info> {
+    /// Anyone can move a stalled round forward
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+}
+
+impl<'info> AdvanceRound<'info> {
+    /// `remaining_accounts` must hold the beneficiary account currently
+    /// holding each of the round's slots, in slot order. Not needed for
+    /// savings clubs.
+    pub fn advance_round(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            !self.config.rotation_complete(),
+            FundCycleError::CycleAlreadyComplete
+        );
+
+        let clock = Clock::get()?;
+
+        // Savings clubs have no turns - rounds simply close once the
+        // interval is up, and contributions stay in the pool
+        if self.config.mode == CycleMode::SavingsClub {
+            require!(
+                clock.unix_timestamp > self.config.round_due_ts(),
+                FundCycleError::PayoutWindowOpen
+            );
+            self.config.advance_round(clock.unix_timestamp)?;
+            msg!(
+                "Savings round closed | Rounds: {}/{}",
+                self.config.rounds_completed,
+                self.config.total_rounds()
+            );
+            return Ok(());
+        }
+
+        let mut recipients = self.round_recipients(remaining_accounts)?;
+
+        // A vacant slot can never withdraw, so a round whose slots are all
+        // vacant is skipped straight away. Otherwise the round's recipients
+        // keep their turn until the window closes.
+        let all_vacant = recipients.iter().all(|recipient| !recipient.active);
+        require!(
+            all_vacant || clock.unix_timestamp > self.config.payout_window_end(),
+            FundCycleError::PayoutWindowOpen
+        );
+
+        // Whatever the round's recipients have not drawn
+        let unpaid_pot = if self.config.round_withdrawals == 0 {
+            self.config.round_pot()?
+        } else {
+            let unpaid = self.config.recipients_in_round() - self.config.round_withdrawals;
+            self.config.round_share
+                .checked_mul(unpaid as u64)
+                .ok_or(FundCycleError::MathOverflow)?
+        };
+        let skipped_index = self.config.current_index;
+
+        let destination = match self.config.skip_policy {
+            // Pot stays in the vault as reserve
+            SkipPolicy::Reserve => "reserve",
+            SkipPolicy::NextRecipient => {
+                self.config.carry_over = self.config.carry_over
+                    .checked_add(unpaid_pot)
+                    .ok_or(FundCycleError::MathOverflow)?;
+                "next recipient"
+            }
+        };
+
+        // Carry-over stays in the contribution pool, the rest is reserve
+        let retained = self.vault.contribution_pool
+            .checked_sub(self.config.carry_over)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.vault.retain_contributions(retained)?;
+
+        // Everyone who did not draw their share loses the turn
+        for recipient in recipients.iter_mut() {
+            if !recipient.payout_received {
+                recipient.skipped = true;
+                recipient.exit(&crate::ID)?;
+            }
+        }
+        self.config.rounds_skipped = self.config.rounds_skipped
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.config.advance_round(clock.unix_timestamp)?;
+
+        msg!(
+            "Round for slot {} skipped | Pot of {} lamports moved to {} | Next slot: {}",
+            skipped_index,
+            unpaid_pot,
+            destination,
+            self.config.current_index
+        );
+
+        Ok(())
+    }
+
+    /// Loads the round's recipients: one account per slot, in order, each
+    /// the slot's current holder rather than a member it replaced
+    fn round_recipients(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>]
+    ) -> Result<Vec<Account<'info, BeneficiaryAccount>>> {
+        require!(
+            remaining_accounts.len() == self.config.recipients_in_round() as usize,
+            FundCycleError::IncompleteBeneficiarySet
+        );
+
+        let mut recipients = Vec::with_capacity(remaining_accounts.len());
+        for (offset, info) in remaining_accounts.iter().enumerate() {
+            let recipient = Account::<BeneficiaryAccount>::try_from(info)?;
+            require_keys_eq!(recipient.config, self.config.key(), FundCycleError::InvalidConfig);
+            require!(
+                recipient.index as usize == self.config.current_index as usize + offset
+                    && !recipient.replaced,
+                FundCycleError::IncompleteBeneficiarySet
+            );
+            recipients.push(recipient);
+        }
+        Ok(recipients)
+    }
+}
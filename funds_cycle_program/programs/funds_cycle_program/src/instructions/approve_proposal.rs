@@ -0,0 +1,46 @@
+// instructions/approve_proposal.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"committee", committee.config.as_ref()],
+        bump = committee.bump
+    )]
+    pub committee: Account<'info, CommitteeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", committee.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.committee == committee.key() @ FundCycleError::InvalidCommittee,
+        constraint = !proposal.executed @ FundCycleError::ProposalAlreadyExecuted,
+        constraint = proposal.epoch == committee.epoch @ FundCycleError::StaleProposal
+    )]
+    pub proposal: Account<'info, ProposalAccount>,
+}
+
+impl<'info> ApproveProposal<'info> {
+    pub fn approve_proposal(&mut self) -> Result<()> {
+        let member_index = self.committee
+            .member_index(&self.member.key())
+            .ok_or(FundCycleError::NotCommitteeMember)?;
+
+        let bit = 1u16 << member_index;
+        require!(self.proposal.approvals & bit == 0, FundCycleError::AlreadyApproved);
+        self.proposal.approvals |= bit;
+
+        msg!(
+            "Proposal {} approved by {} | Approvals: {}/{}",
+            self.proposal.id,
+            self.member.key(),
+            self.proposal.approval_count(),
+            self.committee.threshold
+        );
+
+        Ok(())
+    }
+}
@@ -1,9 +1,8 @@
 // instructions/claim_collateral.rs
 use anchor_lang::prelude::*;
-use anchor_lang::system_program;
-use anchor_lang::system_program::transfer;
-use crate::state::{ConfigAccount, BeneficiaryAccount, VaultAccount};
+use crate::state::{ConfigAccount, BeneficiaryAccount, VaultAccount, CycleMode};
 use crate::error::FundCycleError;
+use crate::utils::{ pay_from_vault, assert_vault_solvent };
 
 #[derive(Accounts)]
 pub struct ClaimCollateral<'info> {
@@ -13,8 +12,9 @@ pub struct ClaimCollateral<'info> {
     
     #[account(
         mut,
-        seeds = [b"config", config.admin.as_ref()],
-        bump = config.bump
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
     )]
     pub config: Account<'info, ConfigAccount>,
     
@@ -26,21 +26,28 @@ pub struct ClaimCollateral<'info> {
     )]
     pub vault: Account<'info, VaultAccount>,
     
-    /// Optional - only needed for claim_collateral function
+    /// Optional - only needed for claim_collateral function. Signed for
+    /// by the member, or by their nominee once the member is dormant.
     #[account(
         mut,
-        seeds = [b"beneficiary", config.key().as_ref(), signer.key().as_ref()],
-        bump,
-        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
-        constraint = beneficiary.wallet == signer.key() @ FundCycleError::InvalidBeneficiaryWallet
+        seeds = [b"beneficiary", config.key().as_ref(), beneficiary.wallet.as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig
     )]
     pub beneficiary: Option<Account<'info, BeneficiaryAccount>>,
+
+    /// CHECK: Only receives lamports - the member's payout destination, or
+    /// the nominee when they are the one claiming. Only needed for claim_collateral.
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
     
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> ClaimCollateral<'info> {
-    pub fn enable_claiming(&mut self) -> Result<()> {
+    /// `remaining_accounts` must hold every active beneficiary account,
+    /// each exactly once, so the residual pool can be sized.
+    pub fn enable_claiming(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
         // Verify admin authorization
         require!(
             self.config.admin == self.signer.key(),
@@ -55,16 +62,48 @@ impl<'info> ClaimCollateral<'info> {
         
         // Check if round robin cycle is complete
         require!(
-            self.config.current_index >= self.config.max_beneficiaries,
+            self.config.rotation_complete(),
             FundCycleError::CycleNotComplete
         );
         
+        require!(
+            remaining_accounts.len() == self.config.active_members as usize,
+            FundCycleError::IncompleteBeneficiarySet
+        );
+
+        // Contributions weight each member's cut of whatever is left over
+        let mut total_contributions: u64 = 0;
+        for (i, info) in remaining_accounts.iter().enumerate() {
+            require!(
+                !remaining_accounts[..i].iter().any(|other| other.key() == info.key()),
+                FundCycleError::IncompleteBeneficiarySet
+            );
+
+            let beneficiary = Account::<BeneficiaryAccount>::try_from(info)?;
+            require_keys_eq!(beneficiary.config, self.config.key(), FundCycleError::InvalidConfig);
+            require!(beneficiary.active, FundCycleError::IncompleteBeneficiarySet);
+
+            total_contributions = total_contributions
+                .checked_add(beneficiary.contributions)
+                .ok_or(FundCycleError::MathOverflow)?;
+        }
+
+        // Reserve, forfeits and any unpaid carry-over belong to the members.
+        // Savings clubs keep the active members' contributions to pay back.
+        let keep_contributions = match self.config.mode {
+            CycleMode::Rotation => 0,
+            CycleMode::SavingsClub => total_contributions,
+        };
+        self.config.residual_pool = self.vault.fold_into_reserve(keep_contributions)?;
+        self.config.residual_weight = total_contributions;
+
         // Enable claiming for all beneficiaries
         self.config.claimable = true;
         
         msg!(
-            "Round robin cycle complete! Claiming enabled for all {} beneficiaries.",
-            self.config.max_beneficiaries
+            "Round robin cycle complete! Claiming enabled for all {} beneficiaries. Residual pool: {}",
+            self.config.max_beneficiaries,
+            self.config.residual_pool
         );
         
         Ok(())
@@ -94,35 +133,45 @@ impl<'info> ClaimCollateral<'info> {
             !beneficiary.collateral_claimed,
             FundCycleError::AlreadyClaimed
         );
-        
-        // Validate vault has enough funds
-        let vault_balance = self.vault.to_account_info().lamports();
-        require!(
-            vault_balance >= self.config.collateral_amount,
-            FundCycleError::InsufficientVaultFunds
+
+        // The member claims to their payout destination. A nominee can
+        // claim for a dormant member, and is paid directly.
+        let now = Clock::get()?.unix_timestamp;
+        let recipient = beneficiary.payout_recipient(&self.config, self.signer.key(), now)?;
+        let destination = self.destination.as_ref()
+            .ok_or(FundCycleError::InvalidPayoutDestination)?;
+        require_keys_eq!(
+            destination.key(),
+            recipient,
+            FundCycleError::InvalidPayoutDestination
         );
         
-        // Transfer collateral from vault to beneficiary
-        let transfer_cpi = system_program::Transfer {
-            from: self.vault.to_account_info(),
-            to: self.signer.to_account_info(),
+        // Collateral owed back, net of any strike fines, plus the
+        // member's cut of the residual pool. The last claim also takes
+        // the rounding left in the reserve, so only rent stays behind.
+        let collateral_balance = beneficiary.collateral_balance(&self.config);
+        let last_claim = self.config.claims_completed.saturating_add(1) >= self.config.active_members;
+        let residual_share = if last_claim {
+            self.vault.reserve
+        } else {
+            self.config.residual_share(beneficiary.contributions)
         };
+        self.vault.debit_collateral(collateral_balance)?;
+        self.vault.debit_reserve(residual_share)?;
+        let mut collateral = collateral_balance
+            .checked_add(residual_share)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        // Savings-club members also get their whole contribution history back
+        if self.config.mode == CycleMode::SavingsClub {
+            self.vault.debit_contributions(beneficiary.contributions)?;
+            collateral = collateral
+                .checked_add(beneficiary.contributions)
+                .ok_or(FundCycleError::MathOverflow)?;
+        }
         
-        let config_key = self.config.key();
-        let seeds = &[
-            b"vault",
-            config_key.as_ref(),
-            &[self.vault.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-        
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.system_program.to_account_info(),
-            transfer_cpi,
-            signer_seeds,
-        );
-        
-        transfer(cpi_ctx, self.config.collateral_amount)?;
+        // Transfer collateral from vault to the recipient
+        pay_from_vault(&self.vault, &destination.to_account_info(), collateral)?;
         
         // Mark as claimed
         beneficiary.collateral_claimed = true;
@@ -133,18 +182,20 @@ impl<'info> ClaimCollateral<'info> {
             .ok_or(FundCycleError::MathOverflow)?;
         
         msg!(
-            "Collateral of {} lamports returned to beneficiary: {} | Progress: {}/{}",
-            self.config.collateral_amount,
-            self.signer.key(),
+            "Collateral and residual of {} lamports for beneficiary {} sent to {} | Progress: {}/{}",
+            collateral,
+            beneficiary.wallet,
+            recipient,
             self.config.claims_completed,
-            self.config.max_beneficiaries
+            self.config.active_members
         );
         
         // Check if all beneficiaries have claimed
-        if self.config.claims_completed >= self.config.max_beneficiaries {
+        if self.config.claims_completed >= self.config.active_members {
             msg!("🎉 All beneficiaries have successfully claimed their collateral!");
         }
         
+        assert_vault_solvent(&self.vault)?;
         Ok(())
     }
     
@@ -154,7 +205,7 @@ impl<'info> ClaimCollateral<'info> {
         
         (
             self.config.claimable,                   // Can beneficiaries claim?
-            self.config.current_index,               // Round robin position
+            self.config.rounds_completed,            // Rounds paid out so far
             self.config.claims_completed,            // How many have claimed
             self.config.max_beneficiaries,           // Total beneficiaries
             vault_balance,                           // Remaining vault balance
@@ -162,17 +213,17 @@ impl<'info> ClaimCollateral<'info> {
     }
     
     pub fn is_cycle_complete(&self) -> bool {
-        self.config.current_index >= self.config.max_beneficiaries
+        self.config.rotation_complete()
     }
     
     pub fn is_all_claimed(&self) -> bool {
-        self.config.claims_completed >= self.config.max_beneficiaries
+        self.config.claims_completed >= self.config.active_members
     }
     
     pub fn get_claim_progress(&self) -> f32 {
-        if self.config.max_beneficiaries == 0 {
+        if self.config.active_members == 0 {
             return 0.0;
         }
-        (self.config.claims_completed as f32 / self.config.max_beneficiaries as f32) * 100.0
+        (self.config.claims_completed as f32 / self.config.active_members as f32) * 100.0
     }
 }
\ No newline at end of file
@@ -0,0 +1,86 @@
+// instructions/claim_dissolution.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::{ assert_vault_solvent, pay_from_vault };
+
+#[derive(Accounts)]
+pub struct ClaimDissolution<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Dissolved @ FundCycleError::NotDissolving
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), signer.key().as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = beneficiary.active @ FundCycleError::InactiveBeneficiary,
+        close = rent_payer
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    /// Whoever paid rent for the beneficiary account gets it back
+    #[account(
+        mut,
+        constraint = rent_payer.key() == beneficiary.payer @ FundCycleError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimDissolution<'info> {
+    pub fn claim_dissolution(&mut self) -> Result<()> {
+        // Share of the whole pool in proportion to net position, so any
+        // surplus reserve goes to members too
+        let net = self.beneficiary.net_position(&self.config);
+        let share = if self.config.dissolution_total == 0 {
+            0
+        } else {
+            let pro_rata = (net as u128)
+                .checked_mul(self.config.dissolution_pool as u128)
+                .ok_or(FundCycleError::MathOverflow)?
+                / self.config.dissolution_total as u128;
+            pro_rata as u64
+        };
+
+        self.vault.debit_reserve(share)?;
+
+        if share > 0 {
+            pay_from_vault(&self.vault, &self.signer.to_account_info(), share)?;
+        }
+
+        self.config.claims_completed = self.config.claims_completed
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Dissolution share of {} lamports (net {}) paid to {} | Progress: {}/{}",
+            share,
+            net,
+            self.signer.key(),
+            self.config.claims_completed,
+            self.config.active_members
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        // Beneficiary account is closed by the close constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+// instructions/claim_vested.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::{ pay_from_vault, assert_vault_solvent };
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    /// Member the stream belongs to, or their nominee once the member is dormant
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.beneficiary.as_ref()],
+        bump = stream.bump,
+        constraint = stream.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub stream: Account<'info, StreamAccount>,
+
+    /// Only needed when a nominee claims, to check the member is dormant
+    #[account(address = stream.beneficiary @ FundCycleError::InvalidBeneficiaryWallet)]
+    pub beneficiary: Option<Account<'info, BeneficiaryAccount>>,
+
+    /// Gets the stream rent back once it is fully claimed
+    #[account(
+        mut,
+        constraint = wallet.key() == stream.wallet @ FundCycleError::InvalidRentPayer
+    )]
+    pub wallet: SystemAccount<'info>,
+
+    /// CHECK: Only receives lamports - fixed on the stream at withdraw, or
+    /// the nominee when they are the one claiming
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn claim_vested(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        let recipient = if self.signer.key() == self.stream.wallet {
+            self.stream.destination
+        } else {
+            let beneficiary = self.beneficiary.as_ref()
+                .ok_or(FundCycleError::MissingBeneficiaryAccount)?;
+            beneficiary.payout_recipient(&self.config, self.signer.key(), clock.unix_timestamp)?
+        };
+        require_keys_eq!(self.destination.key(), recipient, FundCycleError::InvalidPayoutDestination);
+
+        let vested = self.stream.vested(clock.unix_timestamp);
+        let claimable = vested
+            .checked_sub(self.stream.claimed)
+            .ok_or(FundCycleError::MathOverflow)?;
+        require!(claimable > 0, FundCycleError::NothingVested);
+
+        self.vault.debit_vesting(claimable)?;
+        self.stream.claimed = vested;
+
+        pay_from_vault(&self.vault, &self.destination.to_account_info(), claimable)?;
+        assert_vault_solvent(&self.vault)?;
+
+        msg!(
+            "Claimed {} vested lamports | Stream: {}/{}",
+            claimable,
+            self.stream.claimed,
+            self.stream.total
+        );
+
+        // Fully claimed streams hand their rent back to the member, even
+        // when their nominee made the last claim
+        if self.stream.finished() {
+            self.stream.close(self.wallet.to_account_info())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// instructions/close_beneficiary.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct CloseBeneficiary<'info> {
+    /// The member once their collateral is settled, or anyone after exit
+    pub signer: Signer<'info>,
+
+    /// CHECK: Cycle the beneficiary belongs to. Already closed after exit,
+    /// so it is only deserialized while it still exists.
+    #[account(address = beneficiary.config @ FundCycleError::InvalidConfig)]
+    pub config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", beneficiary.config.as_ref(), beneficiary.wallet.as_ref()],
+        bump = beneficiary.bump,
+        close = rent_payer
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    /// Whoever paid rent for the beneficiary account gets it back
+    #[account(
+        mut,
+        constraint = rent_payer.key() == beneficiary.payer @ FundCycleError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+}
+
+impl<'info> CloseBeneficiary<'info> {
+    pub fn close_beneficiary(&mut self) -> Result<()> {
+        // Exit closes the config, after which anyone may sweep
+        let cycle_exited = self.config.owner != &crate::ID || self.config.data_is_empty();
+
+        if !cycle_exited {
+            let config = ConfigAccount::try_deserialize(&mut &self.config.data.borrow()[..])?;
+            require!(!config.paused, FundCycleError::CyclePaused);
+            require_keys_eq!(
+                self.signer.key(),
+                self.beneficiary.wallet,
+                FundCycleError::Unauthorized
+            );
+            require!(self.beneficiary.collateral_claimed, FundCycleError::CollateralNotClaimed);
+            // An early leaver's account is still needed to skip their payout
+            // turn, so it stays open until no more rounds will be paid
+            let settling = config.rotation_complete()
+                || config.claimable
+                || matches!(config.status, CycleStatus::Cancelled | CycleStatus::Dissolved);
+            require!(settling, FundCycleError::CycleNotComplete);
+        }
+
+        msg!(
+            "Beneficiary account of {} closed | Rent returned to {}",
+            self.beneficiary.wallet,
+            self.rent_payer.key()
+        );
+
+        // Beneficiary account is closed by the close constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+// instructions/close_dissolution.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::close_vote_records;
+
+#[derive(Accounts)]
+pub struct CloseDissolution<'info> {
+    /// Anyone can close a dissolution that ran out of time
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"dissolution", config.key().as_ref()],
+        bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, DissolutionProposal>,
+
+    /// Whoever paid the proposal rent gets it back
+    #[account(
+        mut,
+        constraint = proposer.key() == proposal.proposer @ FundCycleError::InvalidRentPayer
+    )]
+    pub proposer: SystemAccount<'info>,
+}
+
+impl<'info> CloseDissolution<'info> {
+    /// A ratified proposal freezes the cycle, so one still open on an
+    /// active cycle past its deadline has failed. `remaining_accounts`
+    /// holds a `(vote_record, voter)` pair for every ratification.
+    pub fn close_dissolution(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(!self.proposal.voting_open(&self.config, clock.unix_timestamp), FundCycleError::VoteWindowOpen);
+
+        close_vote_records(self.proposal.key(), remaining_accounts, self.proposal.votes_for as usize)?;
+
+        msg!(
+            "Dissolution expired | For: {} of {} members",
+            self.proposal.votes_for,
+            self.config.active_members
+        );
+
+        Ok(())
+    }
+}
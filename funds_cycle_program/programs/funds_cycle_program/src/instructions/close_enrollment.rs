@@ -0,0 +1,120 @@
+// instructions/close_enrollment.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::utils::{ assert_vault_solvent, pay_from_vault };
+use crate::error::FundCycleError;
+
+#[derive(Accounts)]
+pub struct CloseEnrollment<'info> {
+    /// Anyone - starting or cancelling is permissionless once the deadline passes
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// Optional - only needed for refund_collateral
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), signer.key().as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = beneficiary.wallet == signer.key() @ FundCycleError::InvalidBeneficiaryWallet
+    )]
+    pub beneficiary: Option<Account<'info, BeneficiaryAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseEnrollment<'info> {
+    /// Starts a cycle that did not fill up but reached `min_participants`
+    pub fn start_cycle(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(self.config.status == CycleStatus::Enrollment, FundCycleError::EnrollmentClosed);
+        require!(
+            self.config.enrollment_deadline_passed(clock.unix_timestamp),
+            FundCycleError::EnrollmentStillOpen
+        );
+        require!(
+            self.config.current_index >= self.config.min_required(),
+            FundCycleError::UnderSubscribed
+        );
+
+        self.config.start_cycle(clock.unix_timestamp);
+
+        msg!("Enrollment closed, cycle started with {} beneficiaries", self.config.max_beneficiaries);
+
+        Ok(())
+    }
+
+    /// Cancels a cycle that is still short of `min_participants` at the deadline
+    pub fn cancel_cycle(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(self.config.status == CycleStatus::Enrollment, FundCycleError::EnrollmentClosed);
+        require!(
+            self.config.enrollment_deadline_passed(clock.unix_timestamp),
+            FundCycleError::EnrollmentStillOpen
+        );
+        require!(
+            self.config.current_index < self.config.min_required(),
+            FundCycleError::NotUnderSubscribed
+        );
+
+        self.config.status = CycleStatus::Cancelled;
+
+        msg!(
+            "Cycle cancelled with {}/{} beneficiaries enrolled, collateral can be refunded",
+            self.config.current_index,
+            self.config.min_required()
+        );
+
+        Ok(())
+    }
+
+    pub fn refund_collateral(&mut self) -> Result<()> {
+        require!(self.config.status == CycleStatus::Cancelled, FundCycleError::CycleNotCancelled);
+
+        let beneficiary = self.beneficiary.as_mut()
+            .ok_or(FundCycleError::MissingBeneficiaryAccount)?;
+
+        require!(beneficiary.collateral_paid, FundCycleError::CollateralNotPaid);
+        require!(!beneficiary.collateral_claimed, FundCycleError::AlreadyClaimed);
+
+        self.vault.debit_collateral(self.config.collateral_amount)?;
+
+        pay_from_vault(
+            &self.vault,
+            &self.signer.to_account_info(),
+            self.config.collateral_amount
+        )?;
+
+        beneficiary.collateral_claimed = true;
+        self.config.claims_completed = self.config.claims_completed
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Collateral of {} lamports refunded to: {} | Progress: {}/{}",
+            self.config.collateral_amount,
+            self.signer.key(),
+            self.config.claims_completed,
+            self.config.collaterals_posted
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+}
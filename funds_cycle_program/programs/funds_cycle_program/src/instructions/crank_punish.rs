@@ -0,0 +1,90 @@
+// instructions/crank_punish.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::utils::{ assert_vault_solvent, pay_from_vault };
+use crate::error::FundCycleError;
+
+#[derive(Accounts)]
+pub struct CrankPunish<'info> {
+    /// Anyone - receives the keeper bounty
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive,
+        constraint = config.punish_mode == PunishMode::Admin @ FundCycleError::PunishVoteRequired
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), beneficiary.wallet.as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CrankPunish<'info> {
+    pub fn crank_punish(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(!self.config.rotation_complete(), FundCycleError::CycleAlreadyComplete);
+        require!(self.beneficiary.collateral_paid, FundCycleError::CollateralNotPaid);
+
+        // Provably overdue: past the round's due date and nothing paid for it
+        require!(
+            self.beneficiary.is_overdue(&self.config, clock.unix_timestamp),
+            FundCycleError::PaymentStillOnTime
+        );
+
+        let deactivated = self.beneficiary.record_strike(&mut self.config, &mut self.vault)?;
+
+        // Bounty comes out of whatever collateral the fine left over
+        let bounty = self.config.keeper_bounty
+            .min(self.beneficiary.collateral_balance(&self.config));
+
+        if bounty > 0 {
+            self.beneficiary.fines_paid = self.beneficiary.fines_paid
+                .checked_add(bounty)
+                .ok_or(FundCycleError::MathOverflow)?;
+
+            // A deactivated member's collateral has already been forfeited
+            if !deactivated {
+                self.vault.forfeit_collateral(bounty)?;
+            }
+            // Accrued as a fee, then paid out of that bucket
+            self.vault.accrue_fee(bounty)?;
+            self.vault.debit_fees(bounty)?;
+
+            pay_from_vault(&self.vault, &self.keeper.to_account_info(), bounty)?;
+        }
+
+        msg!(
+            "Keeper {} punished {} | Strike {}/{} | Bounty: {} lamports | Active: {}",
+            self.keeper.key(),
+            self.beneficiary.wallet,
+            self.beneficiary.strikes,
+            self.config.max_strikes.max(1),
+            bounty,
+            !deactivated
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// instructions/create_committee.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct CreateCommittee<'info> {
+    /// Current admin - hands admin rights over to the committee
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"committee", config.key().as_ref()],
+        bump,
+        space = 8 + CommitteeAccount::INIT_SPACE
+    )]
+    pub committee: Account<'info, CommitteeAccount>,
+
+    /// Data-less PDA that becomes `config.admin`. Signs privileged
+    /// instructions on behalf of the committee once a proposal passes.
+    #[account(
+        seeds = [b"committee_authority", committee.key().as_ref()],
+        bump
+    )]
+    pub authority: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateCommittee<'info> {
+    pub fn create_committee(
+        &mut self,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        bumps: &CreateCommitteeBumps
+    ) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COMMITTEE_MEMBERS,
+            FundCycleError::InvalidCommittee
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            FundCycleError::InvalidCommittee
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(!members[..i].contains(member), FundCycleError::InvalidCommittee);
+        }
+
+        self.committee.set_inner(CommitteeAccount {
+            config: self.config.key(),
+            members,
+            threshold,
+            proposal_count: 0,
+            bump: bumps.committee,
+            authority_bump: bumps.authority,
+            epoch: 0,
+        });
+
+        // Committee authority takes over as admin
+        self.config.admin = self.authority.key();
+        self.config.pending_admin = None;
+
+        msg!(
+            "Committee created: {}-of-{} | Admin authority: {}",
+            threshold,
+            self.committee.members.len(),
+            self.authority.key()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+// instructions/create_proposal.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    /// Committee member filing the proposal - counts as the first approval
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"committee", committee.config.as_ref()],
+        bump = committee.bump
+    )]
+    pub committee: Account<'info, CommitteeAccount>,
+
+    #[account(
+        init,
+        payer = member,
+        seeds = [b"proposal", committee.key().as_ref(), committee.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + ProposalAccount::INIT_SPACE
+    )]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateProposal<'info> {
+    pub fn create_proposal(
+        &mut self,
+        action: ProposalAction,
+        bumps: &CreateProposalBumps
+    ) -> Result<()> {
+        let member_index = self.committee
+            .member_index(&self.member.key())
+            .ok_or(FundCycleError::NotCommitteeMember)?;
+
+        let id = self.committee.proposal_count;
+
+        self.proposal.set_inner(ProposalAccount {
+            committee: self.committee.key(),
+            id,
+            proposer: self.member.key(),
+            action,
+            approvals: 1 << member_index,
+            executed: false,
+            bump: bumps.proposal,
+            epoch: self.committee.epoch,
+        });
+
+        self.committee.proposal_count = id
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Proposal {} filed by {} | Approvals: {}/{}",
+            id,
+            self.member.key(),
+            self.proposal.approval_count(),
+            self.committee.threshold
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{ transfer, Transfer };
-use crate::state::{ ConfigAccount, VaultAccount, BeneficiaryAccount };
+use crate::state::{ ConfigAccount, VaultAccount, BeneficiaryAccount, CycleStatus };
 use crate::error::FundCycleError;
+use crate::utils::assert_vault_solvent;
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
     #[account(mut)]
     pub wallet: Signer<'info>,
 
-    #[account(seeds = [b"config", config.admin.as_ref()], bump = config.bump)]
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
+    )]
     pub config: Account<'info, ConfigAccount>,
 
     #[account(
@@ -32,6 +38,11 @@ pub struct Deposit<'info> {
 
 impl<'info> Deposit<'info> {
     pub fn deposit_collateral(&mut self) -> Result<()> {
+        // Collateral only goes in while the cycle is filling or running
+        require!(
+            matches!(self.config.status, CycleStatus::Enrollment | CycleStatus::Active),
+            FundCycleError::CycleNotActive
+        );
         require!(!self.beneficiary.collateral_paid, FundCycleError::CollateralAlreadyPaid);
 
         let cpi_program = self.system_program.to_account_info();
@@ -43,14 +54,22 @@ impl<'info> Deposit<'info> {
 
         transfer(cpi_ctx, self.config.collateral_amount)?;
 
+        self.vault.credit_collateral(self.config.collateral_amount)?;
         self.beneficiary.collateral_paid = true;
+        self.config.collaterals_posted = self.config.collaterals_posted
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        assert_vault_solvent(&self.vault)?;
         Ok(())
     }
 
     pub fn deposit_monthly(&mut self) -> Result<()> {
+        require!(self.config.status == CycleStatus::Active, FundCycleError::CycleNotActive);
         require!(self.beneficiary.collateral_paid, FundCycleError::CollateralNotPaid);
         require!(self.beneficiary.active, FundCycleError::InactiveBeneficiary);
-        require!(!self.beneficiary.monthly_paid, FundCycleError::AlreadyPaidMonthly);
+        let round = self.config.rounds_completed;
+        require!(!self.beneficiary.has_paid_round(round), FundCycleError::AlreadyPaidMonthly);
 
         // Process payment
         let cpi_program = self.system_program.to_account_info();
@@ -62,8 +81,36 @@ impl<'info> Deposit<'info> {
         transfer(cpi_ctx, self.config.monthly_payout)?;
 
         let clock = Clock::get()?;
+
+        // On-time rounds build a streak that wears strikes down
+        if clock.unix_timestamp <= self.config.round_due_ts() {
+            self.beneficiary.on_time_streak = self.beneficiary.on_time_streak.saturating_add(1);
+            let decay_rounds = self.config.strike_decay_rounds;
+            if decay_rounds > 0
+                && self.beneficiary.on_time_streak >= decay_rounds
+                && self.beneficiary.strikes > 0
+            {
+                self.beneficiary.strikes -= 1;
+                self.beneficiary.on_time_streak = 0;
+                msg!("Strike cleared for {} | Strikes: {}", self.wallet.key(), self.beneficiary.strikes);
+            }
+        } else {
+            self.beneficiary.on_time_streak = 0;
+        }
+
         self.beneficiary.monthly_paid = true;
+        self.beneficiary.paid_round = round;
+        self.beneficiary.contributions = self.beneficiary.contributions
+            .checked_add(self.config.monthly_payout)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.vault.credit_contributions(self.config.monthly_payout)?;
+        self.config.round_collected = self.config.round_collected
+            .checked_add(self.config.monthly_payout)
+            .ok_or(FundCycleError::MathOverflow)?;
         self.beneficiary.last_payment_ts = clock.unix_timestamp;
+        self.beneficiary.paused_secs_at_payment = self.config.paused_secs;
+
+        assert_vault_solvent(&self.vault)?;
         Ok(())
     }
 }
@@ -0,0 +1,136 @@
+// instructions/early_exit.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::FundCycleError;
+use crate::utils::{ assert_vault_solvent, pay_from_vault, percent_of };
+
+#[derive(Accounts)]
+pub struct EarlyExit<'info> {
+    /// The member leaving the cycle
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive,
+        constraint = !config.claimable @ FundCycleError::AlreadyClaimable
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), wallet.key().as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.wallet == wallet.key() @ FundCycleError::Unauthorized,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// CHECK: Dissolution proposal PDA - must not exist, members cannot
+    /// leave while a dissolution vote counts them
+    #[account(
+        seeds = [b"dissolution", config.key().as_ref()],
+        bump,
+        constraint = dissolution.data_is_empty() @ FundCycleError::DissolutionPending
+    )]
+    pub dissolution: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EarlyExit<'info> {
+    pub fn early_exit(&mut self) -> Result<()> {
+        require!(self.beneficiary.active, FundCycleError::InactiveBeneficiary);
+        require!(self.beneficiary.collateral_paid, FundCycleError::CollateralNotPaid);
+        require!(!self.beneficiary.payout_received, FundCycleError::AlreadyReceivedPayout);
+        require!(!self.config.rotation_complete(), FundCycleError::CycleAlreadyComplete);
+
+        // 1) Collateral back (net of strike fines), minus the forfeited share
+        let collateral = self.beneficiary.collateral_balance(&self.config);
+        let collateral_forfeit = percent_of(collateral, self.config.exit_forfeit_percent)?;
+        let collateral_refund = collateral
+            .checked_sub(collateral_forfeit)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        // 2) Contributions back, minus the penalty. In a savings club they
+        //    are all still in the pool; in a rotation only what the ledger
+        //    still holds for the member comes back, the rest was paid out
+        let (in_pool, in_reserve) = match self.config.mode {
+            CycleMode::Rotation => self.beneficiary.held_contributions(&self.config)?,
+            CycleMode::SavingsClub => (self.beneficiary.contributions, 0),
+        };
+        let pool_penalty = percent_of(in_pool, self.config.exit_penalty_percent)?;
+        let reserve_penalty = percent_of(in_reserve, self.config.exit_penalty_percent)?;
+        let penalty = pool_penalty
+            .checked_add(reserve_penalty)
+            .ok_or(FundCycleError::MathOverflow)?;
+        let contribution_refund = in_pool
+            .checked_add(in_reserve)
+            .ok_or(FundCycleError::MathOverflow)?
+            .checked_sub(penalty)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        let refund = collateral_refund
+            .checked_add(contribution_refund)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        // Only the liquid reserve can pay out; what is deployed or staked
+        // has to be recalled first
+        let reserve_refund = in_reserve - reserve_penalty;
+        require!(
+            reserve_refund <= self.vault.reserve
+                || (self.vault.reserve_deployed == 0 && self.vault.reserve_staked == 0),
+            FundCycleError::ReserveDeployed
+        );
+
+        // Collateral refund comes from collateral, contributions from the
+        // bucket they are held in, with the penalty kept as reserve. A
+        // payment still in the pool no longer counts towards the round pot.
+        self.vault.debit_collateral(collateral_refund)?;
+        self.vault.forfeit_collateral(collateral_forfeit)?;
+        self.vault.debit_contributions(in_pool - pool_penalty)?;
+        self.vault.retain_contributions(pool_penalty)?;
+        self.vault.debit_reserve(reserve_refund)?;
+        if self.config.mode == CycleMode::Rotation {
+            self.config.round_collected = self.config.round_collected
+                .checked_sub(in_pool)
+                .ok_or(FundCycleError::MathOverflow)?;
+        }
+
+        if refund > 0 {
+            pay_from_vault(&self.vault, &self.wallet.to_account_info(), refund)?;
+        }
+
+        // 3) Free the slot - the rotation pays out to active members only
+        self.beneficiary.active = false;
+        self.beneficiary.exited = true;
+        self.beneficiary.collateral_claimed = true;
+        self.beneficiary.monthly_paid = false;
+        self.config.active_members = self.config.active_members
+            .checked_sub(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Beneficiary {} exited slot {} early | Refunded: {} lamports | Forfeited: {} collateral, {} contributions",
+            self.wallet.key(),
+            self.beneficiary.index,
+            refund,
+            collateral_forfeit,
+            penalty
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+// instructions/execute_proposal.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{ AccountMeta, Instruction };
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::InstructionData;
+use crate::program::FundsCycleProgram;
+use crate::{ error::FundCycleError, instruction, state::* };
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// Any committee member can execute an approved proposal
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = config.admin == authority.key() @ FundCycleError::Unauthorized
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"committee", config.key().as_ref()],
+        bump = committee.bump
+    )]
+    pub committee: Account<'info, CommitteeAccount>,
+
+    #[account(
+        seeds = [b"committee_authority", committee.key().as_ref()],
+        bump = committee.authority_bump
+    )]
+    pub authority: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", committee.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.committee == committee.key() @ FundCycleError::InvalidCommittee,
+        constraint = !proposal.executed @ FundCycleError::ProposalAlreadyExecuted,
+        constraint = proposal.epoch == committee.epoch @ FundCycleError::StaleProposal
+    )]
+    pub proposal: Account<'info, ProposalAccount>,
+
+    pub funds_cycle_program: Program<'info, FundsCycleProgram>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    /// `remaining_accounts` are the accounts of the privileged instruction,
+    /// in its declared order, with the committee authority as the admin.
+    /// Rent for any account it creates is paid by a signing `payer` among
+    /// them, never by the authority. Committee rotation takes no accounts.
+    pub fn execute_proposal(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        require!(
+            self.committee.member_index(&self.member.key()).is_some(),
+            FundCycleError::NotCommitteeMember
+        );
+        require!(
+            self.proposal.approval_count() >= self.committee.threshold,
+            FundCycleError::ThresholdNotMet
+        );
+
+        self.proposal.executed = true;
+
+        match self.proposal.action {
+            ProposalAction::AddMember { member } => self.committee.add_member(member)?,
+            ProposalAction::RemoveMember { member } => self.committee.remove_member(member)?,
+            ProposalAction::SetThreshold { threshold } => self.committee.set_threshold(threshold)?,
+            action => self.invoke_as_admin(action, remaining_accounts)?,
+        }
+
+        msg!("Proposal {} executed by {}", self.proposal.id, self.member.key());
+
+        Ok(())
+    }
+
+    /// Calls the approved admin instruction with the committee authority signing
+    fn invoke_as_admin(
+        &self,
+        action: ProposalAction,
+        remaining_accounts: &[AccountInfo<'info>]
+    ) -> Result<()> {
+        // Instruction data, plus the accounts that must sit at fixed
+        // positions. Every instruction takes the admin first and the
+        // config second, except fill_vacancy where the new member's
+        // wallet comes in between.
+        let (data, config_at, targets) = match action {
+            ProposalAction::AddBeneficiary { wallet } => {
+                (instruction::AddBeneficiary {}.data(), 1, vec![(3, wallet)])
+            }
+            ProposalAction::Punish { beneficiary } => {
+                (instruction::Punish {}.data(), 1, vec![(2, beneficiary)])
+            }
+            ProposalAction::EnableClaiming => (instruction::EnableClaiming {}.data(), 1, vec![]),
+            ProposalAction::Exit => (instruction::Exit {}.data(), 1, vec![]),
+            ProposalAction::Pause => (instruction::Pause {}.data(), 1, vec![]),
+            ProposalAction::Unpause => (instruction::Unpause {}.data(), 1, vec![]),
+            ProposalAction::RemoveBeneficiary { beneficiary } => {
+                (instruction::RemoveBeneficiary {}.data(), 1, vec![(3, beneficiary)])
+            }
+            ProposalAction::FillVacancy { vacated, wallet } => {
+                (instruction::FillVacancy {}.data(), 2, vec![(1, wallet), (4, vacated)])
+            }
+            ProposalAction::UpdateConfig { args } => {
+                (instruction::UpdateConfig { args }.data(), 1, vec![])
+            }
+            ProposalAction::ProposePunishment { beneficiary } => {
+                (instruction::ProposePunishment {}.data(), 1, vec![(3, beneficiary)])
+            }
+            ProposalAction::ProposeDissolution => {
+                (instruction::ProposeDissolution {}.data(), 1, vec![])
+            }
+            ProposalAction::DeployReserve { amount } => {
+                (instruction::DeployReserve { amount }.data(), 1, vec![])
+            }
+            ProposalAction::RecallReserve { amount } => {
+                (instruction::RecallReserve { amount }.data(), 1, vec![])
+            }
+            ProposalAction::StakeReserve { amount, vote_account } => {
+                (instruction::StakeReserve { amount }.data(), 1, vec![(4, vote_account)])
+            }
+            ProposalAction::DeactivateReserveStake => {
+                (instruction::DeactivateReserveStake {}.data(), 1, vec![])
+            }
+            ProposalAction::ProposeAdmin { new_admin } => {
+                (instruction::ProposeAdmin { new_admin }.data(), 1, vec![])
+            }
+            ProposalAction::AddMember { .. }
+            | ProposalAction::RemoveMember { .. }
+            | ProposalAction::SetThreshold { .. } => unreachable!("applied to the committee directly"),
+        };
+
+        let expected = [(0, self.authority.key()), (config_at, self.config.key())]
+            .into_iter()
+            .chain(targets);
+        for (position, key) in expected {
+            let account = remaining_accounts
+                .get(position)
+                .ok_or(FundCycleError::ProposalAccountMismatch)?;
+            require_keys_eq!(account.key(), key, FundCycleError::ProposalAccountMismatch);
+        }
+
+        let accounts = remaining_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == self.authority.key(),
+                is_writable: account.is_writable,
+            })
+            .collect();
+
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts,
+            data,
+        };
+
+        let mut account_infos = remaining_accounts.to_vec();
+        account_infos.push(self.funds_cycle_program.to_account_info());
+
+        let committee_key = self.committee.key();
+        let seeds = &[
+            b"committee_authority",
+            committee_key.as_ref(),
+            &[self.committee.authority_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(&ix, &account_infos, signer_seeds)?;
+
+        Ok(())
+    }
+}
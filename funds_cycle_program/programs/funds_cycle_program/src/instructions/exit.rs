@@ -11,12 +11,11 @@ pub struct Exit<'info> {
     
     #[account(
         mut,
-        seeds = [b"config", config.admin.as_ref()],
+        seeds = [b"config", config.creator.as_ref()],
         bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
         constraint = config.admin == admin.key() @ FundCycleError::Unauthorized,
-        constraint = config.claimable @ FundCycleError::ClaimingNotEnabled,
-        constraint = config.claims_completed >= config.max_beneficiaries @ FundCycleError::NotAllClaimed,
-        close = admin  // Close config account and send rent to admin
+        close = creator  // Close config account and send rent to its funder
     )]
     pub config: Account<'info, ConfigAccount>,
     
@@ -25,40 +24,79 @@ pub struct Exit<'info> {
         seeds = [b"vault", config.key().as_ref()],
         bump = vault.bump,
         constraint = vault.config == config.key() @ FundCycleError::InvalidConfig,
-        close = admin  // Close vault account and send remaining funds + rent to admin
+        close = creator  // Close vault account once only rent is left in it
     )]
     pub vault: Account<'info, VaultAccount>,
+
+    /// Paid the config and vault rent at initialize, gets it back
+    #[account(
+        mut,
+        constraint = creator.key() == config.creator @ FundCycleError::InvalidRentPayer
+    )]
+    pub creator: SystemAccount<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> Exit<'info> {
-    pub fn exit(&mut self) -> Result<()> {
-        // Double-check all claims are completed
-        require!(
-            self.config.claims_completed == self.config.max_beneficiaries,
-            FundCycleError::NotAllClaimed
-        );
-        
-        // Get remaining vault balance before closing
-        let remaining_balance = self.vault.to_account_info().lamports();
+    pub fn exit(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        if self.config.status == CycleStatus::Cancelled {
+            // Cancelled cycles only need every posted collateral refunded
+            require!(
+                self.config.claims_completed >= self.config.collaterals_posted,
+                FundCycleError::NotAllClaimed
+            );
+        } else if self.config.status == CycleStatus::Dissolved {
+            // Dissolved cycles need every active member paid their share
+            require!(
+                self.config.claims_completed >= self.config.active_members,
+                FundCycleError::NotAllClaimed
+            );
+        } else {
+            require!(self.config.claimable, FundCycleError::ClaimingNotEnabled);
+
+            // Double-check all claims are completed - punished and exited
+            // members have already settled their collateral
+            require!(
+                self.config.claims_completed >= self.config.active_members,
+                FundCycleError::NotAllClaimed
+            );
+        }
         
+        // Vault is closed below, so every payout stream must be drained first
+        require!(self.vault.vesting == 0, FundCycleError::StreamsOutstanding);
+
+        // Sweep any beneficiary accounts passed along, returning their rent
+        let swept = self.sweep_beneficiaries(remaining_accounts)?;
+
         msg!(
-            "PROGRAM EXIT: All {} beneficiaries claimed collateral successfully!",
-            self.config.max_beneficiaries
+            "PROGRAM EXIT: All {} collateral claims settled successfully!",
+            self.config.claims_completed
         );
-        
+
         msg!(
-            "Returning remaining vault balance of {} lamports to admin: {}",
-            remaining_balance,
-            self.admin.key()
+            "Closed {} beneficiary accounts | Config and vault rent returned to {}",
+            swept,
+            self.creator.key()
         );
-        
-        msg!(" Closing all program accounts and returning rent to admin");
-        
+
         // Accounts will be automatically closed due to close constraints
-        // Vault balance + rent will go to admin
-        
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// `remaining_accounts` holds optional `(beneficiary, rent_payer)` pairs
+    /// of this cycle's beneficiary accounts to close along with it.
+    fn sweep_beneficiaries(&self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<usize> {
+        require!(remaining_accounts.len().is_multiple_of(2), FundCycleError::InvalidSweepAccounts);
+
+        for pair in remaining_accounts.chunks(2) {
+            let beneficiary = Account::<BeneficiaryAccount>::try_from(&pair[0])?;
+            require_keys_eq!(beneficiary.config, self.config.key(), FundCycleError::InvalidConfig);
+            require_keys_eq!(pair[1].key(), beneficiary.payer, FundCycleError::InvalidRentPayer);
+
+            beneficiary.close(pair[1].clone())?;
+        }
+
+        Ok(remaining_accounts.len() / 2)
+    }
+}
@@ -0,0 +1,138 @@
+// instructions/fill_vacancy.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ transfer, Transfer };
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::assert_vault_solvent;
+
+#[derive(Accounts)]
+pub struct FillVacancy<'info> {
+    /// Admin approves the replacement member
+    pub admin: Signer<'info>,
+
+    /// Replacement member - pays catch-up contributions, collateral and rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive,
+        constraint = !config.claimable @ FundCycleError::AlreadyClaimable,
+        constraint = !config.rotation_complete() @ FundCycleError::CycleAlreadyComplete
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// Punished or exited member whose slot is being taken over
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), vacated.wallet.as_ref()],
+        bump = vacated.bump,
+        constraint = vacated.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = !vacated.active @ FundCycleError::SlotNotVacant,
+        constraint = !vacated.replaced @ FundCycleError::VacancyAlreadyFilled,
+        constraint = !vacated.payout_received @ FundCycleError::AlreadyReceivedPayout,
+        constraint = !vacated.skipped @ FundCycleError::VacancyTurnPassed,
+        constraint = vacated.index >= config.current_index @ FundCycleError::VacancyTurnPassed
+    )]
+    pub vacated: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        init,
+        payer = wallet,
+        seeds = [b"beneficiary", config.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        space = 8 + BeneficiaryAccount::INIT_SPACE
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FillVacancy<'info> {
+    pub fn fill_vacancy(&mut self, bumps: &FillVacancyBumps) -> Result<()> {
+        // Contributions owed for every round already completed
+        let catch_up = self.config.monthly_payout
+            .checked_mul(self.config.rounds_completed as u64)
+            .ok_or(FundCycleError::MathOverflow)?;
+        let amount = catch_up
+            .checked_add(self.config.collateral_amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.wallet.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        transfer(cpi_ctx, amount)?;
+
+        // Replacement inherits the vacated slot and its future payout
+        let index = self.vacated.index;
+        self.beneficiary.set_inner(BeneficiaryAccount {
+            config: self.config.key(),
+            wallet: self.wallet.key(),
+            index,
+            collateral_paid: true,
+            monthly_paid: false,
+            bump: bumps.beneficiary,
+            last_payment_ts: 0,
+            active: true,
+            collateral_claimed: false,
+            payer: self.wallet.key(),
+            contributions: catch_up,
+            payout_received: false,
+            exited: false,
+            replaced: false,
+            skipped: false,
+            paid_round: 0,
+            strikes: 0,
+            last_strike_round: None,
+            on_time_streak: 0,
+            fines_paid: 0,
+            paused_secs_at_payment: self.config.paused_secs,
+            payouts_received: 0,
+            payout_destination: None,
+            nominee: None,
+            last_active_ts: 0,
+            paused_secs_at_active: 0,
+        });
+
+        self.vacated.replaced = true;
+
+        // Catch-up covers rounds already paid out, so it goes to reserve.
+        // Savings clubs pay it back with the rest of the contributions.
+        self.vault.credit_collateral(self.config.collateral_amount)?;
+        match self.config.mode {
+            CycleMode::Rotation => self.vault.credit_reserve(catch_up)?,
+            CycleMode::SavingsClub => self.vault.credit_contributions(catch_up)?,
+        }
+
+        self.config.collaterals_posted = self.config.collaterals_posted
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.config.active_members = self.config.active_members
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Wallet {} took over slot {} from {} | Catch-up: {} lamports, Collateral: {} lamports",
+            self.wallet.key(),
+            index,
+            self.vacated.wallet,
+            catch_up,
+            self.config.collateral_amount
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+}
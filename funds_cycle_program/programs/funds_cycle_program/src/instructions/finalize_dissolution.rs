@@ -0,0 +1,90 @@
+// instructions/finalize_dissolution.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::close_vote_records;
+
+#[derive(Accounts)]
+pub struct FinalizeDissolution<'info> {
+    /// Anyone can finalize a ratified dissolution
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = config.status == CycleStatus::Dissolving @ FundCycleError::NotDissolving
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"dissolution", config.key().as_ref()],
+        bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, DissolutionProposal>,
+
+    /// Whoever paid the proposal rent gets it back
+    #[account(
+        mut,
+        constraint = proposer.key() == proposal.proposer @ FundCycleError::InvalidRentPayer
+    )]
+    pub proposer: SystemAccount<'info>,
+}
+
+impl<'info> FinalizeDissolution<'info> {
+    /// `remaining_accounts` must hold every active beneficiary account,
+    /// each exactly once, so the net positions can be totalled, followed by
+    /// a `(vote_record, voter)` pair for every ratification.
+    pub fn finalize_dissolution(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            remaining_accounts.len() >= self.config.active_members as usize,
+            FundCycleError::IncompleteBeneficiarySet
+        );
+        let (beneficiaries, vote_records) = remaining_accounts.split_at(self.config.active_members as usize);
+
+        let mut total: u64 = 0;
+        for (i, info) in beneficiaries.iter().enumerate() {
+            require!(
+                !beneficiaries[..i].iter().any(|other| other.key() == info.key()),
+                FundCycleError::IncompleteBeneficiarySet
+            );
+
+            let beneficiary = Account::<BeneficiaryAccount>::try_from(info)?;
+            require_keys_eq!(beneficiary.config, self.config.key(), FundCycleError::InvalidConfig);
+            require!(beneficiary.active, FundCycleError::IncompleteBeneficiarySet);
+
+            total = total
+                .checked_add(beneficiary.net_position(&self.config))
+                .ok_or(FundCycleError::MathOverflow)?;
+        }
+
+        close_vote_records(self.proposal.key(), vote_records, self.proposal.votes_for as usize)?;
+
+        // Every bucket but fees owed is shared out, collateral included
+        let collateral_held = self.vault.collateral_held;
+        self.vault.forfeit_collateral(collateral_held)?;
+        let pool = self.vault.fold_into_reserve(0)?;
+
+        self.config.dissolution_total = total;
+        self.config.dissolution_pool = pool;
+        self.config.status = CycleStatus::Dissolved;
+
+        msg!(
+            "Cycle dissolved | Net owed: {} | Vault pool: {} | Members: {}",
+            total,
+            pool,
+            self.config.active_members
+        );
+
+        Ok(())
+    }
+}
@@ -39,6 +39,8 @@ impl<'info> Initialize<'info> {
         // Config setup
         self.config.set_inner(ConfigAccount {
             admin: self.admin.key(),
+            creator: self.admin.key(),
+            pending_admin: None,
             collateral_amount,
             monthly_payout,
             payment_interval_days,
@@ -48,11 +50,54 @@ impl<'info> Initialize<'info> {
             claimable: false,
             claims_completed: 0,
             bump: bumps.config,
+            status: CycleStatus::Enrollment,
+            enrollment_deadline_ts: 0,
+            min_participants: 0,
+            rounds_completed: 0,
+            collaterals_posted: 0,
+            active_members: 0,
+            exit_forfeit_percent: 0,
+            exit_penalty_percent: 0,
+            round_start_ts: 0,
+            skip_policy: SkipPolicy::Reserve,
+            rounds_skipped: 0,
+            carry_over: 0,
+            punish_mode: PunishMode::Admin,
+            vote_window_days: 0,
+            vote_majority_percent: 0,
+            max_strikes: 1,
+            strike_fine: 0,
+            strike_decay_rounds: 0,
+            keeper_bounty: 0,
+            paused: false,
+            paused_at: 0,
+            paused_secs: 0,
+            dissolution_total: 0,
+            dissolution_pool: 0,
+            residual_pool: 0,
+            residual_weight: 0,
+            round_collected: 0,
+            yield_program: None,
+            mode: CycleMode::Rotation,
+            payouts_per_round: 1,
+            round_withdrawals: 0,
+            round_share: 0,
+            vest_payouts: false,
+            nominee_dormancy_days: 0,
         });
         // Vault setup
         self.vault.set_inner(VaultAccount {
             config: self.config.key(),
             bump: bumps.vault,
+            collateral_held: 0,
+            contribution_pool: 0,
+            reserve: 0,
+            fees_owed: 0,
+            forfeited: 0,
+            reserve_deployed: 0,
+            yield_accrued: 0,
+            reserve_staked: 0,
+            vesting: 0,
         });
 
       
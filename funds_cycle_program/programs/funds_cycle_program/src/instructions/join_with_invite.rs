@@ -0,0 +1,172 @@
+// instructions/join_with_invite.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked,
+    load_instruction_at_checked,
+};
+use crate::{ error::FundCycleError, state::* };
+
+/// Size of the ed25519 program instruction header (count + padding)
+const ED25519_HEADER_LEN: usize = 2;
+/// Size of a single `Ed25519SignatureOffsets` entry (7 x u16)
+const ED25519_OFFSETS_LEN: usize = 14;
+
+#[derive(Accounts)]
+pub struct JoinWithInvite<'info> {
+    /// The invited wallet - joins itself and pays for its own beneficiary account
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Enrollment @ FundCycleError::EnrollmentClosed,
+        constraint = config.current_index < config.max_beneficiaries @ FundCycleError::MaxBeneficiariesReached
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        init,
+        payer = wallet,
+        seeds = [b"beneficiary", config.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        space = 8 + BeneficiaryAccount::INIT_SPACE
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    /// CHECK: instructions sysvar, used to read the ed25519 verify instruction
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> JoinWithInvite<'info> {
+    pub fn join_with_invite(
+        &mut self,
+        slot: u8,
+        expiry_ts: i64,
+        bumps: &JoinWithInviteBumps
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(clock.unix_timestamp <= expiry_ts, FundCycleError::InviteExpired);
+
+        // Invites are issued for a specific slot in the payout order
+        let index = self.config.current_index;
+        require!(slot == index, FundCycleError::InviteSlotMismatch);
+
+        // The admin's signature over the invite must be verified by the
+        // ed25519 program in the instruction right before this one
+        let message = invite_message(&self.config.key(), &self.wallet.key(), slot, expiry_ts);
+        self.verify_admin_signature(&message)?;
+
+        // Save beneficiary info
+        self.beneficiary.set_inner(BeneficiaryAccount {
+            config: self.config.key(),
+            wallet: self.wallet.key(),
+            index,
+            collateral_paid: false,
+            monthly_paid: false,
+            bump: bumps.beneficiary,
+            last_payment_ts: 0,
+            active: true,
+            collateral_claimed: false,
+            payer: self.wallet.key(),
+            contributions: 0,
+            payout_received: false,
+            exited: false,
+            replaced: false,
+            skipped: false,
+            paid_round: 0,
+            strikes: 0,
+            last_strike_round: None,
+            on_time_streak: 0,
+            fines_paid: 0,
+            paused_secs_at_payment: self.config.paused_secs,
+            payouts_received: 0,
+            payout_destination: None,
+            nominee: None,
+            last_active_ts: 0,
+            paused_secs_at_active: 0,
+        });
+
+        // Increase count in config
+        self.config.current_index += 1;
+
+        // Last slot taken - the round robin starts
+        if self.config.current_index == self.config.max_beneficiaries {
+            self.config.start_cycle(Clock::get()?.unix_timestamp);
+        }
+
+        msg!("Wallet {} joined slot {} with admin invite", self.wallet.key(), slot);
+
+        Ok(())
+    }
+
+    fn verify_admin_signature(&self, message: &[u8]) -> Result<()> {
+        let ixs = self.instructions.to_account_info();
+
+        let current_index = load_current_index_checked(&ixs)?;
+        require!(current_index > 0, FundCycleError::InvalidInviteSignature);
+        let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, &ixs)?;
+
+        require_keys_eq!(
+            ed25519_ix.program_id,
+            ed25519_program::ID,
+            FundCycleError::InvalidInviteSignature
+        );
+        require!(ed25519_ix.accounts.is_empty(), FundCycleError::InvalidInviteSignature);
+
+        let data = &ed25519_ix.data;
+        require!(
+            data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+            FundCycleError::InvalidInviteSignature
+        );
+
+        let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+        let offsets = ED25519_HEADER_LEN;
+        let signature_ix_index = read_u16(offsets + 2);
+        let public_key_offset = read_u16(offsets + 4) as usize;
+        let public_key_ix_index = read_u16(offsets + 6);
+        let message_offset = read_u16(offsets + 8) as usize;
+        let message_size = read_u16(offsets + 10) as usize;
+        let message_ix_index = read_u16(offsets + 12);
+
+        // Signature, key and message must all live inside the ed25519 instruction itself
+        require!(
+            signature_ix_index == u16::MAX &&
+                public_key_ix_index == u16::MAX &&
+                message_ix_index == u16::MAX,
+            FundCycleError::InvalidInviteSignature
+        );
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + 32)
+            .ok_or(FundCycleError::InvalidInviteSignature)?;
+        require!(
+            public_key == self.config.admin.as_ref(),
+            FundCycleError::InvalidInviteSignature
+        );
+
+        let signed_message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(FundCycleError::InvalidInviteSignature)?;
+        require!(signed_message == message, FundCycleError::InvalidInviteSignature);
+
+        Ok(())
+    }
+}
+
+/// Invite message signed off-chain by the admin:
+/// config (32) | wallet (32) | slot (1) | expiry_ts (8, little endian)
+pub fn invite_message(config: &Pubkey, wallet: &Pubkey, slot: u8, expiry_ts: i64) -> Vec<u8> {
+    let mut message = Vec::with_capacity(32 + 32 + 1 + 8);
+    message.extend_from_slice(config.as_ref());
+    message.extend_from_slice(wallet.as_ref());
+    message.push(slot);
+    message.extend_from_slice(&expiry_ts.to_le_bytes());
+    message
+}
@@ -4,17 +4,101 @@ pub use initialize::*;
 pub mod add_beneficiary;
 pub use add_beneficiary::*;
 
+pub mod join_with_invite;
+pub use join_with_invite::*;
+
+pub mod remove_beneficiary;
+pub use remove_beneficiary::*;
+
+pub mod transfer_admin;
+pub use transfer_admin::*;
+
+pub mod create_committee;
+pub use create_committee::*;
+
+pub mod create_proposal;
+pub use create_proposal::*;
+
+pub mod approve_proposal;
+pub use approve_proposal::*;
+
+pub mod execute_proposal;
+pub use execute_proposal::*;
+
+pub mod pause;
+pub use pause::*;
+
+pub mod update_config;
+pub use update_config::*;
+
+pub mod close_enrollment;
+pub use close_enrollment::*;
+
 pub mod deposit;
 pub use deposit::*;
 
 pub mod withdraw;
 pub use withdraw::*;
 
+pub mod advance_round;
+pub use advance_round::*;
+
 pub mod punish;
 pub use punish::*;
 
+pub mod early_exit;
+pub use early_exit::*;
+
+pub mod fill_vacancy;
+pub use fill_vacancy::*;
+
+pub mod crank_punish;
+pub use crank_punish::*;
+
+pub mod propose_punishment;
+pub use propose_punishment::*;
+
+pub mod vote_punishment;
+pub use vote_punishment::*;
+
+pub mod resolve_punishment;
+pub use resolve_punishment::*;
+
+pub mod reserve_yield;
+pub use reserve_yield::*;
+
+pub mod stake_reserve;
+pub use stake_reserve::*;
+
+pub mod unstake_reserve;
+pub use unstake_reserve::*;
+
+pub mod propose_dissolution;
+pub use propose_dissolution::*;
+
+pub mod ratify_dissolution;
+pub use ratify_dissolution::*;
+
+pub mod close_dissolution;
+pub use close_dissolution::*;
+
+pub mod finalize_dissolution;
+pub use finalize_dissolution::*;
+
+pub mod claim_dissolution;
+pub use claim_dissolution::*;
+
+pub mod payout_settings;
+pub use payout_settings::*;
+
+pub mod claim_vested;
+pub use claim_vested::*;
+
 pub mod claim_collateral;
 pub use claim_collateral::*;
 
+pub mod close_beneficiary;
+pub use close_beneficiary::*;
+
 pub mod exit;
 pub use exit::*;
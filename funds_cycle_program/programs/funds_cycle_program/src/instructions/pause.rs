@@ -0,0 +1,43 @@
+// instructions/pause.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::FundCycleError;
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// Only admin can pause or unpause
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        has_one = admin
+    )]
+    pub config: Account<'info, ConfigAccount>,
+}
+
+impl<'info> Pause<'info> {
+    pub fn pause(&mut self) -> Result<()> {
+        require!(!self.config.paused, FundCycleError::CyclePaused);
+
+        let clock = Clock::get()?;
+        self.config.paused = true;
+        self.config.paused_at = clock.unix_timestamp;
+
+        msg!("Cycle paused at {}", clock.unix_timestamp);
+
+        Ok(())
+    }
+
+    pub fn unpause(&mut self) -> Result<()> {
+        require!(self.config.paused, FundCycleError::CycleNotPaused);
+
+        // Stop the clock: push every running deadline back by the pause
+        let paused_for = self.config.resume(Clock::get()?.unix_timestamp);
+
+        msg!("Cycle unpaused after {} seconds", paused_for);
+
+        Ok(())
+    }
+}
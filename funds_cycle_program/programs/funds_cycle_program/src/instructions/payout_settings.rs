@@ -0,0 +1,63 @@
+// instructions/payout_settings.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct UpdatePayoutSettings<'info> {
+    /// Only the member can change where their funds go
+    pub wallet: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), wallet.key().as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = !beneficiary.collateral_claimed @ FundCycleError::AlreadyClaimed
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+}
+
+impl<'info> UpdatePayoutSettings<'info> {
+    /// Sets or changes the payout destination. `None` pays the wallet again.
+    pub fn set_payout_destination(&mut self, destination: Option<Pubkey>) -> Result<()> {
+        self.beneficiary.payout_destination = destination;
+        self.beneficiary.last_active_ts = Clock::get()?.unix_timestamp;
+        self.beneficiary.paused_secs_at_active = self.config.paused_secs;
+
+        msg!(
+            "Payouts for {} now go to {}",
+            self.wallet.key(),
+            self.beneficiary.payout_to()
+        );
+
+        Ok(())
+    }
+
+    /// Sets or changes the nominee. `None` removes them.
+    pub fn set_nominee(&mut self, nominee: Option<Pubkey>) -> Result<()> {
+        require!(nominee != Some(self.wallet.key()), FundCycleError::InvalidNominee);
+
+        self.beneficiary.nominee = nominee;
+        self.beneficiary.last_active_ts = Clock::get()?.unix_timestamp;
+        self.beneficiary.paused_secs_at_active = self.config.paused_secs;
+
+        match nominee {
+            Some(nominee) => msg!(
+                "Nominee for {} set to {} | Dormancy period: {} days",
+                self.wallet.key(),
+                nominee,
+                self.config.nominee_dormancy_days
+            ),
+            None => msg!("Nominee for {} removed", self.wallet.key()),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+// instructions/propose_dissolution.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct ProposeDissolution<'info> {
+    /// Only admin can start a dissolution
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        has_one = admin,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive,
+        constraint = !config.claimable @ FundCycleError::AlreadyClaimable,
+        constraint = config.vote_window_days > 0 @ FundCycleError::VoteWindowNotSet
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    /// Pays the rent and gets it back on finalization - kept apart from the admin, which is an unfunded
+    /// PDA when a committee runs the cycle
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"dissolution", config.key().as_ref()],
+        bump,
+        space = 8 + DissolutionProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, DissolutionProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeDissolution<'info> {
+    pub fn propose_dissolution(&mut self, bumps: &ProposeDissolutionBumps) -> Result<()> {
+        let clock = Clock::get()?;
+
+        self.proposal.set_inner(DissolutionProposal {
+            config: self.config.key(),
+            proposer: self.payer.key(),
+            opened_ts: clock.unix_timestamp,
+            closes_ts: clock.unix_timestamp + self.config.vote_window_secs(),
+            votes_for: 0,
+            bump: bumps.proposal,
+            paused_secs_at_open: self.config.paused_secs,
+        });
+
+        msg!(
+            "Dissolution proposed by {} | Needs {} of {} members within {} days",
+            self.admin.key(),
+            self.config.active_members / 2 + 1,
+            self.config.active_members,
+            self.config.vote_window_days
+        );
+
+        Ok(())
+    }
+}
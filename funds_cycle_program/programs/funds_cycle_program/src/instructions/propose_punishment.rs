@@ -0,0 +1,80 @@
+// instructions/propose_punishment.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct ProposePunishment<'info> {
+    /// Only admin can file a punishment proposal
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        has_one = admin,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive,
+        constraint = config.punish_mode == PunishMode::Vote @ FundCycleError::PunishVoteNotEnabled
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    /// Pays the rent and gets it back on resolution - kept apart from the admin, which is an unfunded
+    /// PDA when a committee runs the cycle
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The accused member
+    #[account(
+        seeds = [b"beneficiary", config.key().as_ref(), beneficiary.wallet.as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = beneficiary.active @ FundCycleError::InactiveBeneficiary
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"punishment", config.key().as_ref(), beneficiary.key().as_ref()],
+        bump,
+        space = 8 + PunishmentProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, PunishmentProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposePunishment<'info> {
+    pub fn propose_punishment(&mut self, bumps: &ProposePunishmentBumps) -> Result<()> {
+        let clock = Clock::get()?;
+
+        require!(!self.config.rotation_complete(), FundCycleError::CycleAlreadyComplete);
+        require!(self.beneficiary.collateral_paid, FundCycleError::CollateralNotPaid);
+
+        // Same overdue check as a direct punish
+        require!(
+            self.beneficiary.is_overdue(&self.config, clock.unix_timestamp),
+            FundCycleError::PaymentStillOnTime
+        );
+
+        self.proposal.set_inner(PunishmentProposal {
+            config: self.config.key(),
+            beneficiary: self.beneficiary.key(),
+            proposer: self.payer.key(),
+            opened_ts: clock.unix_timestamp,
+            eligible_voters: self.config.active_members.saturating_sub(1),
+            votes_for: 0,
+            votes_against: 0,
+            bump: bumps.proposal,
+            paused_secs_at_open: self.config.paused_secs,
+        });
+
+        msg!(
+            "Punishment vote opened against {} | Eligible voters: {} | Window: {} days",
+            self.beneficiary.wallet,
+            self.proposal.eligible_voters,
+            self.config.vote_window_days
+        );
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{ConfigAccount, BeneficiaryAccount};
+use crate::state::{ConfigAccount, BeneficiaryAccount, CycleStatus, PunishMode, VaultAccount};
 use crate::error::FundCycleError;
+use crate::utils::assert_vault_solvent;
 
 #[derive(Accounts)]
 pub struct Punish<'info> {
@@ -9,8 +10,10 @@ pub struct Punish<'info> {
     pub admin: Signer<'info>,
 
     #[account(
-        seeds = [b"config", config.admin.as_ref()],
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
         bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
         constraint = config.admin == admin.key() @ FundCycleError::Unauthorized
     )]
     pub config: Account<'info, ConfigAccount>,
@@ -22,25 +25,44 @@ pub struct Punish<'info> {
         constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig
     )]
     pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
 }
 
 impl<'info> Punish<'info> {
     pub fn punish(&mut self) -> Result<()> {
+        require!(self.config.status == CycleStatus::Active, FundCycleError::CycleNotActive);
+        require!(self.config.punish_mode == PunishMode::Admin, FundCycleError::PunishVoteRequired);
+        require!(!self.config.rotation_complete(), FundCycleError::CycleAlreadyComplete);
+
         let clock = Clock::get()?;
 
-        // Calculate next due date
-        let payment_due_ts = self.beneficiary.last_payment_ts
-            + (self.config.payment_interval_days as i64 * 86400); // days → seconds
+        require!(self.beneficiary.collateral_paid, FundCycleError::CollateralNotPaid);
 
-        // Check if overdue
+        // Same overdue check as the keeper crank
         require!(
-            clock.unix_timestamp > payment_due_ts,
+            self.beneficiary.is_overdue(&self.config, clock.unix_timestamp),
             FundCycleError::PaymentStillOnTime
         );
 
-        // Mark inactive (punished)
-        self.beneficiary.active = false;
+        let deactivated = self.beneficiary.record_strike(&mut self.config, &mut self.vault)?;
+
+        msg!(
+            "Strike {}/{} for {} | Fines paid: {} lamports | Active: {}",
+            self.beneficiary.strikes,
+            self.config.max_strikes.max(1),
+            self.beneficiary.wallet,
+            self.beneficiary.fines_paid,
+            !deactivated
+        );
 
+        assert_vault_solvent(&self.vault)?;
         Ok(())
     }
 }
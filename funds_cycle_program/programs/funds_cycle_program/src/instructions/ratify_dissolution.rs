@@ -0,0 +1,78 @@
+// instructions/ratify_dissolution.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct RatifyDissolution<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    /// Voter's own beneficiary account - only active members ratify
+    #[account(
+        seeds = [b"beneficiary", config.key().as_ref(), voter.key().as_ref()],
+        bump = voter_beneficiary.bump,
+        constraint = voter_beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = voter_beneficiary.active @ FundCycleError::InactiveBeneficiary
+    )]
+    pub voter_beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"dissolution", config.key().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, DissolutionProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+        space = 8 + VoteRecord::INIT_SPACE
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RatifyDissolution<'info> {
+    pub fn ratify_dissolution(&mut self, bumps: &RatifyDissolutionBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(self.proposal.voting_open(&self.config, clock.unix_timestamp), FundCycleError::VoteWindowClosed);
+
+        self.vote_record.set_inner(VoteRecord {
+            proposal: self.proposal.key(),
+            voter: self.voter.key(),
+            approve: true,
+            bump: bumps.vote_record,
+        });
+
+        self.proposal.votes_for = self.proposal.votes_for
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        // Simple majority of active members freezes the cycle
+        if (self.proposal.votes_for as u16) * 2 > self.config.active_members as u16 {
+            self.config.status = CycleStatus::Dissolving;
+            msg!("Dissolution ratified | Cycle frozen pending finalize_dissolution");
+        }
+
+        msg!(
+            "Dissolution ratified by {} | For: {} of {} members",
+            self.voter.key(),
+            self.proposal.votes_for,
+            self.config.active_members
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+// instructions/remove_beneficiary.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::{ assert_vault_solvent, pay_from_vault };
+
+#[derive(Accounts)]
+pub struct RemoveBeneficiary<'info> {
+    /// Admin, or the member backing out themselves
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Enrollment @ FundCycleError::EnrollmentClosed
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), beneficiary.wallet.as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        close = rent_payer
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    /// Receives any collateral already posted
+    #[account(
+        mut,
+        constraint = wallet.key() == beneficiary.wallet @ FundCycleError::InvalidBeneficiaryWallet
+    )]
+    pub wallet: SystemAccount<'info>,
+
+    /// Whoever paid rent for the beneficiary account gets it back
+    #[account(
+        mut,
+        constraint = rent_payer.key() == beneficiary.payer @ FundCycleError::InvalidRentPayer
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    /// Beneficiary in the last slot, moved into the freed slot.
+    /// Not needed when the removed beneficiary already holds the last slot.
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), last_beneficiary.wallet.as_ref()],
+        bump = last_beneficiary.bump,
+        constraint = last_beneficiary.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub last_beneficiary: Option<Account<'info, BeneficiaryAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RemoveBeneficiary<'info> {
+    pub fn remove_beneficiary(&mut self) -> Result<()> {
+        let signer = self.signer.key();
+        require!(
+            signer == self.config.admin || signer == self.beneficiary.wallet,
+            FundCycleError::Unauthorized
+        );
+
+        // Refund collateral if it was already posted
+        if self.beneficiary.collateral_paid && !self.beneficiary.collateral_claimed {
+            self.vault.debit_collateral(self.config.collateral_amount)?;
+
+            pay_from_vault(
+                &self.vault,
+                &self.wallet.to_account_info(),
+                self.config.collateral_amount
+            )?;
+
+            self.config.collaterals_posted = self.config.collaterals_posted
+                .checked_sub(1)
+                .ok_or(FundCycleError::MathOverflow)?;
+        }
+
+        // Compact slots: the last slot moves into the freed one
+        let last_index = self.config.current_index
+            .checked_sub(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+        let removed_index = self.beneficiary.index;
+
+        if removed_index != last_index {
+            let last_beneficiary = self.last_beneficiary.as_mut()
+                .ok_or(FundCycleError::MissingBeneficiaryAccount)?;
+            require!(last_beneficiary.index == last_index, FundCycleError::LastSlotMismatch);
+
+            last_beneficiary.index = removed_index;
+        }
+
+        self.config.current_index = last_index;
+
+        msg!(
+            "Beneficiary {} removed from slot {} | Slots filled: {}/{}",
+            self.beneficiary.wallet,
+            removed_index,
+            self.config.current_index,
+            self.config.max_beneficiaries
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        // Beneficiary account is closed by the close constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+// instructions/reserve_yield.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::{ assert_vault_solvent, pay_from_vault };
+use crate::yield_interface::{ yield_deposit_ix, yield_withdraw_ix };
+
+#[derive(Accounts)]
+pub struct ManageReserve<'info> {
+    /// Only admin moves the reserve in and out of the yield program
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// A dissolving cycle can still recall, so the reserve is liquid to finalize
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        has_one = admin,
+        constraint = matches!(
+            config.status,
+            CycleStatus::Active | CycleStatus::Dissolving
+        ) @ FundCycleError::CycleNotActive
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// CHECK: Must match `config.yield_program`, see `yield_interface`
+    #[account(
+        constraint = config.yield_program.is_some() @ FundCycleError::YieldProgramNotSet,
+        constraint = config.yield_program == Some(yield_program.key()) @ FundCycleError::InvalidYieldProgram
+    )]
+    pub yield_program: UncheckedAccount<'info>,
+
+    /// CHECK: Position account owned and validated by the yield program
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ManageReserve<'info> {
+    pub fn deploy_reserve(&mut self, amount: u64) -> Result<()> {
+        require!(self.config.status == CycleStatus::Active, FundCycleError::CycleNotActive);
+        require!(amount > 0, FundCycleError::InvalidYieldAmount);
+
+        self.vault.debit_reserve(amount)?;
+        self.vault.reserve_deployed = self.vault.reserve_deployed
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        // Only this program can debit the vault, so the lamports are moved
+        // here and the yield program is just told about the deposit
+        pay_from_vault(&self.vault, &self.position.to_account_info(), amount)?;
+
+        let ix = yield_deposit_ix(
+            self.yield_program.key(),
+            self.vault.key(),
+            self.position.key(),
+            amount
+        );
+        self.invoke_yield_program(&ix)?;
+
+        msg!(
+            "Deployed {} lamports of reserve | Deployed: {} | Reserve left: {}",
+            amount,
+            self.vault.reserve_deployed,
+            self.vault.reserve
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+
+    /// Recalls `amount` of deployed principal. Anything returned above
+    /// it is yield, anything short of it is booked as a loss.
+    pub fn recall_reserve(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount > 0 && amount <= self.vault.reserve_deployed,
+            FundCycleError::InvalidYieldAmount
+        );
+
+        let balance_before = self.vault.to_account_info().lamports();
+
+        let ix = yield_withdraw_ix(
+            self.yield_program.key(),
+            self.vault.key(),
+            self.position.key(),
+            amount
+        );
+        self.invoke_yield_program(&ix)?;
+
+        let received = self.vault.to_account_info().lamports()
+            .checked_sub(balance_before)
+            .ok_or(FundCycleError::MathOverflow)?;
+        let principal = received.min(amount);
+        let earned = received - principal;
+
+        self.vault.reserve_deployed -= amount;
+        self.vault.credit_reserve(principal)?;
+        self.vault.yield_accrued = self.vault.yield_accrued
+            .checked_add(earned)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Recalled {} lamports of reserve | Yield: {} | Loss: {} | Still deployed: {}",
+            principal,
+            earned,
+            amount - principal,
+            self.vault.reserve_deployed
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+
+    fn invoke_yield_program(&self, ix: &Instruction) -> Result<()> {
+        let config_key = self.config.key();
+        let seeds = &[
+            b"vault",
+            config_key.as_ref(),
+            &[self.vault.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(
+            ix,
+            &[
+                self.vault.to_account_info(),
+                self.position.to_account_info(),
+                self.system_program.to_account_info(),
+                self.yield_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
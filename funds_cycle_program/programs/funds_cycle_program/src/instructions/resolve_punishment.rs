@@ -0,0 +1,102 @@
+// instructions/resolve_punishment.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::{assert_vault_solvent, close_vote_records};
+
+#[derive(Accounts)]
+pub struct ResolvePunishment<'info> {
+    /// Anyone can resolve once the voting window has closed
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"beneficiary", config.key().as_ref(), beneficiary.wallet.as_ref()],
+        bump = beneficiary.bump,
+        constraint = beneficiary.key() == proposal.beneficiary @ FundCycleError::InvalidBeneficiaryWallet
+    )]
+    pub beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"punishment", config.key().as_ref(), proposal.beneficiary.as_ref()],
+        bump = proposal.bump,
+        close = proposer
+    )]
+    pub proposal: Account<'info, PunishmentProposal>,
+
+    /// Whoever paid the proposal rent gets it back
+    #[account(
+        mut,
+        constraint = proposer.key() == proposal.proposer @ FundCycleError::InvalidRentPayer
+    )]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+}
+
+impl<'info> ResolvePunishment<'info> {
+    /// `remaining_accounts` holds a `(vote_record, voter)` pair for every
+    /// vote cast, so the records close along with the proposal.
+    pub fn resolve_punishment(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            !self.proposal.voting_open(&self.config, clock.unix_timestamp),
+            FundCycleError::VoteWindowOpen
+        );
+
+        let votes = self.proposal.votes_for as usize + self.proposal.votes_against as usize;
+        close_vote_records(self.proposal.key(), remaining_accounts, votes)?;
+
+        // Majority is measured against everyone who could vote, not just votes cast
+        let majority_reached = (self.proposal.votes_for as u16) * 100
+            >= (self.proposal.eligible_voters as u16) * (self.config.vote_majority_percent as u16)
+            && self.proposal.votes_for > 0;
+
+        // Paying during the vote cures the default
+        let still_overdue = self.beneficiary.is_overdue(&self.config, clock.unix_timestamp);
+
+        let round = self.config.rounds_completed;
+        if majority_reached && still_overdue && self.beneficiary.active
+            && !self.beneficiary.struck_in_round(round)
+        {
+            let deactivated = self.beneficiary.record_strike(&mut self.config, &mut self.vault)?;
+
+            msg!(
+                "Punishment upheld: strike {}/{} for {} | Active: {}",
+                self.beneficiary.strikes,
+                self.config.max_strikes.max(1),
+                self.beneficiary.wallet,
+                !deactivated
+            );
+        } else if !still_overdue {
+            msg!("Punishment dropped: {} cured the default", self.beneficiary.wallet);
+        } else {
+            msg!(
+                "Punishment rejected: {} of {} votes needed {}%",
+                self.proposal.votes_for,
+                self.proposal.eligible_voters,
+                self.config.vote_majority_percent
+            );
+        }
+
+        assert_vault_solvent(&self.vault)?;
+
+        // Proposal account is closed by the close constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+// instructions/stake_reserve.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake::{ self, state::{ Authorized, Lockup, StakeStateV2 } };
+use anchor_lang::solana_program::sysvar::stake_history;
+use anchor_lang::system_program::{ allocate, assign, Allocate, Assign };
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::{ assert_vault_solvent, pay_from_vault };
+
+#[derive(Accounts)]
+pub struct StakeReserve<'info> {
+    /// Only admin picks the validator and how much reserve to stake
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused,
+        has_one = admin,
+        constraint = config.status == CycleStatus::Active @ FundCycleError::CycleNotActive
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// CHECK: Stake account PDA, created here and owned by the stake program
+    #[account(
+        mut,
+        seeds = [b"reserve_stake", config.key().as_ref()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validator vote account, checked by the stake program
+    pub vote_account: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Stake config account, still expected by delegate_stake
+    #[account(address = stake::config::ID)]
+    pub stake_config: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeReserve<'info> {
+    /// Moves `amount` of reserve into a fresh stake account, with the vault
+    /// as staker and withdrawer, and delegates it to `vote_account`
+    pub fn stake_reserve(&mut self, amount: u64, bumps: &StakeReserveBumps) -> Result<()> {
+        require!(self.vault.reserve_staked == 0, FundCycleError::ReserveAlreadyStaked);
+        // Leave the final round for cooldown so funds are liquid at the end
+        require!(
+            self.config.rounds_completed.saturating_add(1) < self.config.total_rounds(),
+            FundCycleError::FinalRoundStarted
+        );
+
+        // Enough to keep the stake account rent exempt and still delegate
+        // the stake program's minimum
+        let stake_space = StakeStateV2::size_of();
+        let minimum_delegation = stake::tools::get_minimum_delegation()?;
+        let minimum_stake = self.rent.minimum_balance(stake_space)
+            .checked_add(minimum_delegation)
+            .ok_or(FundCycleError::MathOverflow)?;
+        require!(amount >= minimum_stake, FundCycleError::InvalidYieldAmount);
+
+        self.vault.debit_reserve(amount)?;
+        self.vault.reserve_staked = amount;
+
+        let config_key = self.config.key();
+        let vault_seeds = &[
+            b"vault",
+            config_key.as_ref(),
+            &[self.vault.bump],
+        ];
+        let stake_seeds = &[
+            b"reserve_stake",
+            config_key.as_ref(),
+            &[bumps.stake_account],
+        ];
+
+        let stake_signer_seeds = &[&stake_seeds[..]];
+
+        // 1) Fund the stake account straight from the vault - the system
+        //    program cannot debit a program-owned account that holds data -
+        //    then size it and hand it to the stake program
+        pay_from_vault(&self.vault, &self.stake_account.to_account_info(), amount)?;
+        allocate(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Allocate { account_to_allocate: self.stake_account.to_account_info() },
+                stake_signer_seeds,
+            ),
+            stake_space as u64,
+        )?;
+        assign(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Assign { account_to_assign: self.stake_account.to_account_info() },
+                stake_signer_seeds,
+            ),
+            &stake::program::ID,
+        )?;
+
+        // 2) Vault PDA is both staker and withdrawer
+        let vault_key = self.vault.key();
+        let ix = stake::instruction::initialize(
+            &self.stake_account.key(),
+            &Authorized { staker: vault_key, withdrawer: vault_key },
+            &Lockup::default(),
+        );
+        invoke_signed(
+            &ix,
+            &[
+                self.stake_account.to_account_info(),
+                self.rent.to_account_info(),
+                self.stake_program.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+
+        // 3) Delegate to the chosen validator
+        let ix = stake::instruction::delegate_stake(
+            &self.stake_account.key(),
+            &vault_key,
+            &self.vote_account.key(),
+        );
+        invoke_signed(
+            &ix,
+            &[
+                self.stake_account.to_account_info(),
+                self.vote_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_config.to_account_info(),
+                self.vault.to_account_info(),
+                self.stake_program.to_account_info(),
+            ],
+            &[&vault_seeds[..]],
+        )?;
+
+        msg!(
+            "Staked {} lamports of reserve with {} | Reserve left: {}",
+            amount,
+            self.vote_account.key(),
+            self.vault.reserve
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+}
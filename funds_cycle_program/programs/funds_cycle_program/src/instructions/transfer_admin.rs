@@ -0,0 +1,48 @@
+// instructions/transfer_admin.rs
+use anchor_lang::prelude::*;
+use crate::state::ConfigAccount;
+use crate::error::FundCycleError;
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    /// Current admin for propose_admin, proposed admin for accept_admin
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ConfigAccount>,
+}
+
+impl<'info> TransferAdmin<'info> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        require!(
+            self.config.admin == self.signer.key(),
+            FundCycleError::Unauthorized
+        );
+
+        // Proposing again replaces any earlier pending proposal
+        self.config.pending_admin = Some(new_admin);
+
+        msg!("Admin handover proposed: {} -> {}", self.config.admin, new_admin);
+
+        Ok(())
+    }
+
+    pub fn accept_admin(&mut self) -> Result<()> {
+        require!(
+            self.config.pending_admin == Some(self.signer.key()),
+            FundCycleError::NoPendingAdmin
+        );
+
+        let previous_admin = self.config.admin;
+        self.config.admin = self.signer.key();
+        self.config.pending_admin = None;
+
+        msg!("Admin handover complete: {} -> {}", previous_admin, self.config.admin);
+
+        Ok(())
+    }
+}
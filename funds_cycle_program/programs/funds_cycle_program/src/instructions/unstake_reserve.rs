@@ -0,0 +1,146 @@
+// instructions/unstake_reserve.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::stake;
+use anchor_lang::solana_program::sysvar::stake_history;
+use crate::{ error::FundCycleError, state::* };
+use crate::utils::assert_vault_solvent;
+
+#[derive(Accounts)]
+pub struct UnstakeReserve<'info> {
+    /// Admin at any time, or anyone once the final round has started or
+    /// the cycle is dissolving
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", config.key().as_ref()],
+        bump = vault.bump,
+        constraint = vault.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = vault.reserve_staked > 0 @ FundCycleError::NoReserveStaked
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// CHECK: Stake account PDA created by stake_reserve
+    #[account(
+        mut,
+        seeds = [b"reserve_stake", config.key().as_ref()],
+        bump
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+}
+
+impl<'info> UnstakeReserve<'info> {
+    /// Starts the stake cooldown. Anyone can crank it once the final round
+    /// has started or a dissolution was ratified, so the reserve is liquid
+    /// for settlement.
+    pub fn deactivate_reserve_stake(&mut self) -> Result<()> {
+        let final_round = self.config.rounds_completed.saturating_add(1)
+            >= self.config.total_rounds();
+        let dissolving = self.config.status == CycleStatus::Dissolving;
+        require!(
+            final_round || dissolving || self.signer.key() == self.config.admin,
+            FundCycleError::Unauthorized
+        );
+
+        let vault_key = self.vault.key();
+        let ix = stake::instruction::deactivate_stake(&self.stake_account.key(), &vault_key);
+        self.invoke_stake_program(
+            &ix,
+            &[
+                self.stake_account.to_account_info(),
+                self.clock.to_account_info(),
+                self.vault.to_account_info(),
+                self.stake_program.to_account_info(),
+            ],
+        )?;
+
+        msg!("Reserve stake deactivating | Staked: {}", self.vault.reserve_staked);
+
+        Ok(())
+    }
+
+    /// Pulls the whole stake account back into the vault once cooled down.
+    /// Anything above the staked principal is rewards for the members.
+    pub fn withdraw_reserve_stake(&mut self) -> Result<()> {
+        let stake_lamports = self.stake_account.lamports();
+        let balance_before = self.vault.to_account_info().lamports();
+
+        let vault_key = self.vault.key();
+        let ix = stake::instruction::withdraw(
+            &self.stake_account.key(),
+            &vault_key,
+            &vault_key,
+            stake_lamports,
+            None,
+        );
+        self.invoke_stake_program(
+            &ix,
+            &[
+                self.stake_account.to_account_info(),
+                self.vault.to_account_info(),
+                self.clock.to_account_info(),
+                self.stake_history.to_account_info(),
+                self.stake_program.to_account_info(),
+            ],
+        )?;
+
+        let received = self.vault.to_account_info().lamports()
+            .checked_sub(balance_before)
+            .ok_or(FundCycleError::MathOverflow)?;
+        let staked = self.vault.reserve_staked;
+        let principal = received.min(staked);
+        let rewards = received - principal;
+
+        self.vault.reserve_staked = 0;
+        self.vault.credit_reserve(principal)?;
+        self.vault.yield_accrued = self.vault.yield_accrued
+            .checked_add(rewards)
+            .ok_or(FundCycleError::MathOverflow)?;
+
+        msg!(
+            "Reserve stake withdrawn | Principal: {} | Rewards: {} | Loss: {}",
+            principal,
+            rewards,
+            staked - principal
+        );
+
+        assert_vault_solvent(&self.vault)?;
+        Ok(())
+    }
+
+    fn invoke_stake_program(
+        &self,
+        ix: &Instruction,
+        account_infos: &[AccountInfo<'info>]
+    ) -> Result<()> {
+        let config_key = self.config.key();
+        let seeds = &[
+            b"vault",
+            config_key.as_ref(),
+            &[self.vault.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        invoke_signed(ix, account_infos, signer_seeds)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+// instructions/update_config.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+/// Optional cycle terms the admin can set while enrollment is open.
+/// Fields left as `None` keep their current value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct UpdateConfigArgs {
+    pub enrollment_deadline_ts: Option<i64>,
+    pub min_participants: Option<u8>,
+    pub exit_forfeit_percent: Option<u8>,
+    pub exit_penalty_percent: Option<u8>,
+    pub skip_policy: Option<SkipPolicy>,
+    pub punish_mode: Option<PunishMode>,
+    pub vote_window_days: Option<u16>,
+    pub vote_majority_percent: Option<u8>,
+    pub max_strikes: Option<u8>,
+    pub strike_fine: Option<u64>,
+    pub strike_decay_rounds: Option<u8>,
+    pub keeper_bounty: Option<u64>,
+    pub yield_program: Option<Pubkey>,
+    pub mode: Option<CycleMode>,
+    pub payouts_per_round: Option<u8>,
+    pub vest_payouts: Option<bool>,
+    pub nominee_dormancy_days: Option<u16>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        constraint = config.status == CycleStatus::Enrollment @ FundCycleError::EnrollmentClosed
+    )]
+    pub config: Account<'info, ConfigAccount>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(&mut self, args: UpdateConfigArgs) -> Result<()> {
+        if let Some(enrollment_deadline_ts) = args.enrollment_deadline_ts {
+            let clock = Clock::get()?;
+            require!(
+                enrollment_deadline_ts == 0 || enrollment_deadline_ts > clock.unix_timestamp,
+                FundCycleError::InvalidEnrollmentTerms
+            );
+            self.config.enrollment_deadline_ts = enrollment_deadline_ts;
+        }
+
+        if let Some(min_participants) = args.min_participants {
+            require!(
+                min_participants <= self.config.max_beneficiaries,
+                FundCycleError::InvalidEnrollmentTerms
+            );
+            self.config.min_participants = min_participants;
+        }
+
+        if let Some(exit_forfeit_percent) = args.exit_forfeit_percent {
+            require!(exit_forfeit_percent <= 100, FundCycleError::InvalidExitTerms);
+            self.config.exit_forfeit_percent = exit_forfeit_percent;
+        }
+
+        if let Some(exit_penalty_percent) = args.exit_penalty_percent {
+            require!(exit_penalty_percent <= 100, FundCycleError::InvalidExitTerms);
+            self.config.exit_penalty_percent = exit_penalty_percent;
+        }
+
+        if let Some(skip_policy) = args.skip_policy {
+            self.config.skip_policy = skip_policy;
+        }
+
+        if let Some(punish_mode) = args.punish_mode {
+            self.config.punish_mode = punish_mode;
+        }
+
+        if let Some(vote_window_days) = args.vote_window_days {
+            self.config.vote_window_days = vote_window_days;
+        }
+
+        if let Some(vote_majority_percent) = args.vote_majority_percent {
+            require!(
+                vote_majority_percent > 0 && vote_majority_percent <= 100,
+                FundCycleError::InvalidPunishTerms
+            );
+            self.config.vote_majority_percent = vote_majority_percent;
+        }
+
+        if let Some(max_strikes) = args.max_strikes {
+            self.config.max_strikes = max_strikes;
+        }
+
+        if let Some(strike_fine) = args.strike_fine {
+            require!(
+                strike_fine <= self.config.collateral_amount,
+                FundCycleError::InvalidPunishTerms
+            );
+            self.config.strike_fine = strike_fine;
+        }
+
+        if let Some(strike_decay_rounds) = args.strike_decay_rounds {
+            self.config.strike_decay_rounds = strike_decay_rounds;
+        }
+
+        if let Some(keeper_bounty) = args.keeper_bounty {
+            require!(
+                keeper_bounty <= self.config.collateral_amount,
+                FundCycleError::InvalidPunishTerms
+            );
+            self.config.keeper_bounty = keeper_bounty;
+        }
+
+        if let Some(yield_program) = args.yield_program {
+            self.config.yield_program = Some(yield_program);
+        }
+
+        if let Some(mode) = args.mode {
+            self.config.mode = mode;
+        }
+
+        if let Some(payouts_per_round) = args.payouts_per_round {
+            require!(
+                payouts_per_round > 0 && payouts_per_round <= self.config.max_beneficiaries,
+                FundCycleError::InvalidPayoutSplit
+            );
+            self.config.payouts_per_round = payouts_per_round;
+        }
+
+        if let Some(vest_payouts) = args.vest_payouts {
+            self.config.vest_payouts = vest_payouts;
+        }
+
+        if let Some(nominee_dormancy_days) = args.nominee_dormancy_days {
+            self.config.nominee_dormancy_days = nominee_dormancy_days;
+        }
+
+        // Vote mode needs a majority to be reachable
+        require!(
+            self.config.punish_mode == PunishMode::Admin || self.config.vote_majority_percent > 0,
+            FundCycleError::InvalidPunishTerms
+        );
+        // ...and a window to vote in
+        require!(
+            self.config.punish_mode == PunishMode::Admin || self.config.vote_window_days > 0,
+            FundCycleError::VoteWindowNotSet
+        );
+
+        Ok(())
+    }
+}
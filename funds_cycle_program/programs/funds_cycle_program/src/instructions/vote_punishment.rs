@@ -0,0 +1,82 @@
+// instructions/vote_punishment.rs
+use anchor_lang::prelude::*;
+use crate::{ error::FundCycleError, state::* };
+
+#[derive(Accounts)]
+pub struct VotePunishment<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    /// Voter's own beneficiary account - only active members vote
+    #[account(
+        seeds = [b"beneficiary", config.key().as_ref(), voter.key().as_ref()],
+        bump = voter_beneficiary.bump,
+        constraint = voter_beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
+        constraint = voter_beneficiary.active @ FundCycleError::InactiveBeneficiary,
+        constraint = voter_beneficiary.key() != proposal.beneficiary @ FundCycleError::AccusedCannotVote
+    )]
+    pub voter_beneficiary: Account<'info, BeneficiaryAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"punishment", config.key().as_ref(), proposal.beneficiary.as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, PunishmentProposal>,
+
+    #[account(
+        init,
+        payer = voter,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+        space = 8 + VoteRecord::INIT_SPACE
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VotePunishment<'info> {
+    pub fn vote_punishment(&mut self, approve: bool, bumps: &VotePunishmentBumps) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            self.proposal.voting_open(&self.config, clock.unix_timestamp),
+            FundCycleError::VoteWindowClosed
+        );
+
+        self.vote_record.set_inner(VoteRecord {
+            proposal: self.proposal.key(),
+            voter: self.voter.key(),
+            approve,
+            bump: bumps.vote_record,
+        });
+
+        if approve {
+            self.proposal.votes_for = self.proposal.votes_for
+                .checked_add(1)
+                .ok_or(FundCycleError::MathOverflow)?;
+        } else {
+            self.proposal.votes_against = self.proposal.votes_against
+                .checked_add(1)
+                .ok_or(FundCycleError::MathOverflow)?;
+        }
+
+        msg!(
+            "Vote by {}: {} | For: {} Against: {} of {} eligible",
+            self.voter.key(),
+            if approve { "punish" } else { "keep" },
+            self.proposal.votes_for,
+            self.proposal.votes_against,
+            self.proposal.eligible_voters
+        );
+
+        Ok(())
+    }
+}
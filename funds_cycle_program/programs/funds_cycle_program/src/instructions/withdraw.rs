@@ -1,32 +1,33 @@
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{ transfer, Transfer };
 use crate::state::*;
 use crate::error::FundCycleError;
+use crate::utils::{ pay_from_vault, assert_vault_solvent };
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
-    /// The wallet (caller) who must be the beneficiary for this turn
+    /// The beneficiary for this turn, or their nominee once the member is
+    /// dormant. Pays the stream rent when payouts vest.
     #[account(mut)]
-    pub wallet: Signer<'info>,
+    pub signer: Signer<'info>,
 
-    /// Config PDA (seeds: ["config", admin_pubkey])
+    /// Config PDA (seeds: ["config", creator_pubkey])
     #[account(
         mut,
-        seeds = [b"config", config.admin.as_ref()],
-        bump = config.bump
+        seeds = [b"config", config.creator.as_ref()],
+        bump = config.bump,
+        constraint = !config.paused @ FundCycleError::CyclePaused
     )]
     pub config: Account<'info, ConfigAccount>,
 
-    /// Beneficiary PDA for the caller
-    /// seeds = ["beneficiary", config.key(), wallet.key()]
+    /// Beneficiary PDA for this turn
+    /// seeds = ["beneficiary", config.key(), beneficiary.wallet]
     #[account(
         mut,
-        seeds = [b"beneficiary", config.key().as_ref(), wallet.key().as_ref()],
+        seeds = [b"beneficiary", config.key().as_ref(), beneficiary.wallet.as_ref()],
         bump = beneficiary.bump,
-        constraint = beneficiary.wallet == wallet.key() @ FundCycleError::Unauthorized,
         constraint = beneficiary.config == config.key() @ FundCycleError::InvalidConfig,
-        constraint = beneficiary.index == config.current_index @ FundCycleError::NotYourTurn,
-        constraint = beneficiary.monthly_paid == true @ FundCycleError::MonthlyNotPaid
+        constraint = config.is_recipient(beneficiary.index) @ FundCycleError::NotYourTurn,
+        constraint = beneficiary.has_paid_round(config.rounds_completed) @ FundCycleError::MonthlyNotPaid
     )]
     pub beneficiary: Account<'info, BeneficiaryAccount>,
 
@@ -38,65 +39,114 @@ pub struct Withdraw<'info> {
     )]
     pub vault: Account<'info, VaultAccount>,
 
+    /// CHECK: Only receives lamports - the member's payout destination, or
+    /// the nominee when they are the one withdrawing
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Only needed when payouts vest - the payout streams out of it instead
+    #[account(
+        init,
+        payer = signer,
+        seeds = [b"stream", beneficiary.key().as_ref()],
+        bump,
+        space = 8 + StreamAccount::INIT_SPACE
+    )]
+    pub stream: Option<Account<'info, StreamAccount>>,
+
     /// System program for SOL transfer CPI
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> Withdraw<'info> {
-    pub fn withdraw(&mut self) -> Result<()> {
+    pub fn withdraw(&mut self, bumps: &WithdrawBumps) -> Result<()> {
         // basic sanity: must have at least 1 beneficiary configured
 
         require!(self.config.max_beneficiaries > 0, FundCycleError::InvalidConfig);
-        require!(self.beneficiary.active == true, FundCycleError::InactiveBeneficiary);
-
+        require!(self.config.status == CycleStatus::Active, FundCycleError::CycleNotActive);
+        require!(self.config.mode == CycleMode::Rotation, FundCycleError::WithdrawDisabled);
+        require!(
+            !self.config.rotation_complete(),
+            FundCycleError::CycleAlreadyComplete
+        );
+        require!(self.beneficiary.active, FundCycleError::InactiveBeneficiary);
+        require!(!self.beneficiary.payout_received, FundCycleError::AlreadyReceivedPayout);
+
+        // The member is paid to their payout destination, a nominee
+        // withdrawing for a dormant member is paid directly
         let clock = Clock::get()?;
-        let next_withdraw_ts =
-            self.beneficiary.last_payment_ts + (self.config.payment_interval_days as i64) * 86400;
-        require!(clock.unix_timestamp >= next_withdraw_ts, FundCycleError::PaymentStillOnTime);
-
-        // 1) Calculate monthly pool: monthly_payout * max_beneficiaries
-        let monthly_pool = self.config.monthly_payout
-            .checked_mul(self.config.max_beneficiaries as u64)
-            .ok_or(FundCycleError::MathOverflow)?;
-
-        // 2) Payout = monthly_pool * withdraw_percent / 100
-        let payout_amount = monthly_pool
-            .checked_mul(self.config.withdraw_percent as u64)
-            .ok_or(FundCycleError::MathOverflow)?
-            .checked_div(100)
-            .ok_or(FundCycleError::MathOverflow)?;
+        let recipient = self.beneficiary.payout_recipient(&self.config, self.signer.key(), clock.unix_timestamp)?;
+        require_keys_eq!(self.destination.key(), recipient, FundCycleError::InvalidPayoutDestination);
+
+        // Same round clock as advance_round: the pot can be drawn once the
+        // round's contributions fall due, and stays drawable until the
+        // round is advanced past the payout window
+        require!(
+            clock.unix_timestamp >= self.config.round_due_ts(),
+            FundCycleError::PaymentStillOnTime
+        );
+
+        // 1) First withdrawal of the round fixes each recipient's share:
+        //    (round pot + carry-over) / recipients, where the round pot is
+        //    round_collected * withdraw_percent / 100
+        if self.config.round_withdrawals == 0 {
+            let round_pot = self.config.round_pot()?;
+            let pot = round_pot
+                .checked_add(self.config.carry_over)
+                .ok_or(FundCycleError::MathOverflow)?;
+            self.config.round_share = pot / self.config.recipients_in_round() as u64;
+            self.config.carry_over = 0;
+        }
+
+        // 2) Payout = this recipient's share of the pot
+        let payout_amount = self.config.round_share;
 
         require!(payout_amount > 0, FundCycleError::NoFundsAvailable);
 
-        // 3) Ensure vault has enough lamports (protects against under-deposit)
-        let vault_lamports = self.vault.to_account_info().lamports();
-        require!(vault_lamports >= payout_amount, FundCycleError::InsufficientVaultBalance);
-
-        // 4) Transfer SOL from vault PDA -> caller wallet using signed CPI
-        let cpi_program = self.system_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.vault.to_account_info(),
-            to: self.wallet.to_account_info(),
-        };
-
-        // seeds used to derive vault PDA: [b"vault", config.key().as_ref(), &[vault.bump]]
-        let seeds = &[
-            b"vault".as_ref(),
-            self.config.to_account_info().key.as_ref(),
-            &[self.vault.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-
-        transfer(cpi_ctx, payout_amount)?;
-
-        // 5) Reset monthly flag for this beneficiary (they'll need to pay next month again)
-        self.beneficiary.monthly_paid = false;
-
-        // 6) Advance round-robin index (wrap around)
-        self.config.current_index = (self.config.current_index + 1) % self.config.max_beneficiaries;
+        // 3) Pay out of the contribution pool only
+        self.vault.debit_contributions(payout_amount)?;
+
+        // 4) Vested payouts stream out of the vault over one interval,
+        //    otherwise transfer SOL from vault PDA -> payout destination
+        if self.config.vest_payouts {
+            let stream = self.stream.as_mut().ok_or(FundCycleError::MissingStreamAccount)?;
+            stream.set_inner(StreamAccount {
+                config: self.config.key(),
+                beneficiary: self.beneficiary.key(),
+                wallet: self.beneficiary.wallet,
+                destination: self.destination.key(),
+                total: payout_amount,
+                claimed: 0,
+                start_ts: clock.unix_timestamp,
+                duration_secs: self.config.interval_secs(),
+                bump: bumps.stream.ok_or(FundCycleError::MissingStreamAccount)?,
+            });
+            self.vault.credit_vesting(payout_amount)?;
+        } else {
+            // An unused stream would be created and never closed
+            require!(self.stream.is_none(), FundCycleError::UnexpectedStreamAccount);
+            pay_from_vault(&self.vault, &self.destination.to_account_info(), payout_amount)?;
+        }
+
+        // 5) Mark the turn taken. The payment stays recorded against this
+        //    round through `paid_round`, the next round needs a new one
+        self.beneficiary.payout_received = true;
+        self.beneficiary.payouts_received = self.beneficiary.payouts_received
+            .checked_add(payout_amount)
+            .ok_or(FundCycleError::MathOverflow)?;
 
+        // 6) Once every recipient is paid, the rest of the round's
+        //    contributions is kept back as reserve and the round advances
+        self.config.round_withdrawals += 1;
+        if self.config.round_withdrawals >= self.config.recipients_in_round() {
+            let retained = self.vault.contribution_pool
+                .checked_sub(self.config.carry_over)
+                .ok_or(FundCycleError::MathOverflow)?;
+            self.vault.retain_contributions(retained)?;
+            self.config.advance_round(clock.unix_timestamp)?;
+        }
+
+        assert_vault_solvent(&self.vault)?;
         Ok(())
     }
 }
@@ -4,8 +4,11 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod error;
 pub mod instructions;
+pub mod utils;
+pub mod yield_interface;
 
 pub use instructions::*;
+use state::ProposalAction;
 
 declare_id!("BAmKovDnmFfuvXASrEoRa115N3F4QEBCkjUQtRAvkpAj");
 
@@ -36,6 +39,68 @@ pub mod funds_cycle_program {
         ctx.accounts.add_beneficiary(&ctx.bumps)
     }
 
+    pub fn join_with_invite(ctx: Context<JoinWithInvite>, slot: u8, expiry_ts: i64) -> Result<()> {
+        ctx.accounts.join_with_invite(slot, expiry_ts, &ctx.bumps)
+    }
+
+    pub fn remove_beneficiary(ctx: Context<RemoveBeneficiary>) -> Result<()> {
+        ctx.accounts.remove_beneficiary()
+    }
+
+    pub fn propose_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<TransferAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn create_committee(
+        ctx: Context<CreateCommittee>,
+        members: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        ctx.accounts.create_committee(members, threshold, &ctx.bumps)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        ctx.accounts.create_proposal(action, &ctx.bumps)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        ctx.accounts.approve_proposal()
+    }
+
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>
+    ) -> Result<()> {
+        ctx.accounts.execute_proposal(ctx.remaining_accounts)
+    }
+
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        ctx.accounts.pause()
+    }
+
+    pub fn unpause(ctx: Context<Pause>) -> Result<()> {
+        ctx.accounts.unpause()
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        ctx.accounts.update_config(args)
+    }
+
+    pub fn start_cycle(ctx: Context<CloseEnrollment>) -> Result<()> {
+        ctx.accounts.start_cycle()
+    }
+
+    pub fn cancel_cycle(ctx: Context<CloseEnrollment>) -> Result<()> {
+        ctx.accounts.cancel_cycle()
+    }
+
+    pub fn refund_collateral(ctx: Context<CloseEnrollment>) -> Result<()> {
+        ctx.accounts.refund_collateral()
+    }
+
     pub fn deposit_collateral(ctx: Context<Deposit>) -> Result<()> {
         ctx.accounts.deposit_collateral()
     }
@@ -45,15 +110,108 @@ pub mod funds_cycle_program {
     }
 
     pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
-        ctx.accounts.withdraw()
+        ctx.accounts.withdraw(&ctx.bumps)
+    }
+
+    pub fn set_payout_destination(
+        ctx: Context<UpdatePayoutSettings>,
+        destination: Option<Pubkey>
+    ) -> Result<()> {
+        ctx.accounts.set_payout_destination(destination)
+    }
+
+    pub fn set_nominee(ctx: Context<UpdatePayoutSettings>, nominee: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.set_nominee(nominee)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.claim_vested()
+    }
+
+    pub fn advance_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AdvanceRound<'info>>
+    ) -> Result<()> {
+        ctx.accounts.advance_round(ctx.remaining_accounts)
     }
 
     pub fn punish(ctx: Context<Punish>) -> Result<()> {
         ctx.accounts.punish()
     }
 
-    pub fn enable_claiming(ctx: Context<ClaimCollateral>) -> Result<()> {
-        ctx.accounts.enable_claiming()?;
+    pub fn early_exit(ctx: Context<EarlyExit>) -> Result<()> {
+        ctx.accounts.early_exit()
+    }
+
+    pub fn fill_vacancy(ctx: Context<FillVacancy>) -> Result<()> {
+        ctx.accounts.fill_vacancy(&ctx.bumps)
+    }
+
+    pub fn crank_punish(ctx: Context<CrankPunish>) -> Result<()> {
+        ctx.accounts.crank_punish()
+    }
+
+    pub fn propose_punishment(ctx: Context<ProposePunishment>) -> Result<()> {
+        ctx.accounts.propose_punishment(&ctx.bumps)
+    }
+
+    pub fn vote_punishment(ctx: Context<VotePunishment>, approve: bool) -> Result<()> {
+        ctx.accounts.vote_punishment(approve, &ctx.bumps)
+    }
+
+    pub fn resolve_punishment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolvePunishment<'info>>
+    ) -> Result<()> {
+        ctx.accounts.resolve_punishment(ctx.remaining_accounts)
+    }
+
+    pub fn deploy_reserve(ctx: Context<ManageReserve>, amount: u64) -> Result<()> {
+        ctx.accounts.deploy_reserve(amount)
+    }
+
+    pub fn recall_reserve(ctx: Context<ManageReserve>, amount: u64) -> Result<()> {
+        ctx.accounts.recall_reserve(amount)
+    }
+
+    pub fn stake_reserve(ctx: Context<StakeReserve>, amount: u64) -> Result<()> {
+        ctx.accounts.stake_reserve(amount, &ctx.bumps)
+    }
+
+    pub fn deactivate_reserve_stake(ctx: Context<UnstakeReserve>) -> Result<()> {
+        ctx.accounts.deactivate_reserve_stake()
+    }
+
+    pub fn withdraw_reserve_stake(ctx: Context<UnstakeReserve>) -> Result<()> {
+        ctx.accounts.withdraw_reserve_stake()
+    }
+
+    pub fn propose_dissolution(ctx: Context<ProposeDissolution>) -> Result<()> {
+        ctx.accounts.propose_dissolution(&ctx.bumps)
+    }
+
+    pub fn ratify_dissolution(ctx: Context<RatifyDissolution>) -> Result<()> {
+        ctx.accounts.ratify_dissolution(&ctx.bumps)
+    }
+
+    pub fn close_dissolution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseDissolution<'info>>
+    ) -> Result<()> {
+        ctx.accounts.close_dissolution(ctx.remaining_accounts)
+    }
+
+    pub fn finalize_dissolution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeDissolution<'info>>
+    ) -> Result<()> {
+        ctx.accounts.finalize_dissolution(ctx.remaining_accounts)
+    }
+
+    pub fn claim_dissolution(ctx: Context<ClaimDissolution>) -> Result<()> {
+        ctx.accounts.claim_dissolution()
+    }
+
+    pub fn enable_claiming<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCollateral<'info>>
+    ) -> Result<()> {
+        ctx.accounts.enable_claiming(ctx.remaining_accounts)?;
         Ok(())
     }
      pub fn claim_collateral(ctx: Context<ClaimCollateral>) -> Result<()> {
@@ -61,7 +219,11 @@ pub mod funds_cycle_program {
 
     }
 
-    pub fn exit(ctx: Context<Exit>) -> Result<()> {
-        ctx.accounts.exit()
+    pub fn close_beneficiary(ctx: Context<CloseBeneficiary>) -> Result<()> {
+        ctx.accounts.close_beneficiary()
+    }
+
+    pub fn exit<'info>(ctx: Context<'_, '_, 'info, 'info, Exit<'info>>) -> Result<()> {
+        ctx.accounts.exit(ctx.remaining_accounts)
     }
 }
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::error::FundCycleError;
+use crate::state::{ ConfigAccount, VaultAccount };
+use crate::utils::percent_of;
 
 #[account]
 #[derive(InitSpace)]
@@ -12,4 +15,321 @@ pub struct BeneficiaryAccount {
     pub last_payment_ts: i64,    
     pub active: bool,            
     pub collateral_claimed: bool,
+    pub payer: Pubkey,           // paid the rent for this account
+    pub contributions: u64,      // total monthly contributions paid
+    pub payout_received: bool,
+    pub exited: bool,            // left early via early_exit
+    pub replaced: bool,          // vacated slot taken over via fill_vacancy
+    pub skipped: bool,           // payout turn skipped via advance_round
+    pub paid_round: u8,          // round the monthly_paid flag refers to
+    pub strikes: u8,
+    pub last_strike_round: Option<u8>,
+    pub on_time_streak: u8,      // consecutive on-time rounds since last strike
+    pub fines_paid: u64,         // deducted from collateral by strikes
+    pub paused_secs_at_payment: i64, // config.paused_secs when last_payment_ts was set
+    pub payouts_received: u64,   // total lamports paid out by withdraw
+    pub payout_destination: Option<Pubkey>, // receives payouts and claims instead of the wallet
+    pub nominee: Option<Pubkey>, // may claim once the member has been dormant long enough
+    pub last_active_ts: i64,     // last time the member updated their payout settings
+    pub paused_secs_at_active: i64, // config.paused_secs when last_active_ts was set
+}
+
+impl BeneficiaryAccount {
+    pub fn has_paid_round(&self, round: u8) -> bool {
+        self.monthly_paid && self.paid_round == round
+    }
+
+    /// Provably overdue: the member posted collateral, the rotation is still
+    /// running, the current round's due date has passed and it is unpaid.
+    /// A recipient who already drew this round's pot is never overdue for it.
+    /// Every punishment path judges lateness by this one round clock.
+    pub fn is_overdue(&self, config: &ConfigAccount, now: i64) -> bool {
+        let paid_out_this_round = self.payout_received && config.is_recipient(self.index);
+        self.collateral_paid
+            && !config.rotation_complete()
+            && now > config.round_due_ts()
+            && !self.has_paid_round(config.rounds_completed)
+            && !paid_out_this_round
+    }
+
+    /// Where the member's own payouts and claims are sent
+    pub fn payout_to(&self) -> Pubkey {
+        self.payout_destination.unwrap_or(self.wallet)
+    }
+
+    /// Dormant once neither a payment nor a settings update has been seen
+    /// for `nominee_dormancy_days`. Time the cycle spent paused since then
+    /// does not count. Never dormant when that is 0.
+    pub fn is_dormant(&self, config: &ConfigAccount, now: i64) -> bool {
+        let (last_seen, paused_then) = if self.last_payment_ts >= self.last_active_ts {
+            (self.last_payment_ts, self.paused_secs_at_payment)
+        } else {
+            (self.last_active_ts, self.paused_secs_at_active)
+        };
+        let paused_since = config.paused_secs - paused_then;
+        config.nominee_dormancy_days != 0
+            && now > last_seen + config.dormancy_secs() + paused_since
+    }
+
+    /// Who a payout or claim signed by `signer` goes to: the member's
+    /// payout destination when they sign themselves, or the nominee, paid
+    /// directly, once the member is dormant.
+    pub fn payout_recipient(&self, config: &ConfigAccount, signer: Pubkey, now: i64) -> Result<Pubkey> {
+        if signer == self.wallet {
+            return Ok(self.payout_to());
+        }
+        require!(
+            self.nominee == Some(signer) && self.is_dormant(config, now),
+            FundCycleError::NomineeNotEligible
+        );
+        Ok(signer)
+    }
+
+    pub fn struck_in_round(&self, round: u8) -> bool {
+        self.last_strike_round == Some(round)
+    }
+
+    /// Collateral still owed back to the member after fines. Nothing is
+    /// owed to a member who never posted collateral.
+    pub fn collateral_balance(&self, config: &ConfigAccount) -> u64 {
+        if !self.collateral_paid {
+            return 0;
+        }
+        config.collateral_amount.saturating_sub(self.fines_paid)
+    }
+
+    /// What the member is owed if the cycle dissolves now: contributions
+    /// and remaining collateral, minus payouts already received
+    pub fn net_position(&self, config: &ConfigAccount) -> u64 {
+        self.contributions
+            .saturating_add(self.collateral_balance(config))
+            .saturating_sub(self.payouts_received)
+    }
+
+    /// Where a rotation member's contributions still sit in the vault:
+    /// this round's payment while the pot is undrawn, and the share of each
+    /// earlier payment kept back as reserve. The rest was paid out to
+    /// recipients. Returns `(in_pool, in_reserve)`.
+    pub fn held_contributions(&self, config: &ConfigAccount) -> Result<(u64, u64)> {
+        let this_round = if self.has_paid_round(config.rounds_completed) {
+            config.monthly_payout
+        } else {
+            0
+        };
+        let in_pool = if config.round_withdrawals == 0 { this_round } else { 0 };
+        let earlier = self.contributions
+            .checked_sub(this_round)
+            .ok_or(FundCycleError::MathOverflow)?;
+        let in_reserve = percent_of(earlier, 100u8.saturating_sub(config.withdraw_percent))?;
+        Ok((in_pool, in_reserve))
+    }
+
+    /// Records a strike for the current round and deducts the configured
+    /// fine from collateral. Deactivates the member on reaching
+    /// `max_strikes`, forfeiting what is left of their collateral.
+    /// Returns whether the member was deactivated.
+    pub fn record_strike(
+        &mut self,
+        config: &mut ConfigAccount,
+        vault: &mut VaultAccount
+    ) -> Result<bool> {
+        let round = config.rounds_completed;
+        require!(!self.struck_in_round(round), FundCycleError::StrikeAlreadyIssued);
+        require!(self.active, FundCycleError::InactiveBeneficiary);
+
+        self.strikes = self.strikes
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.last_strike_round = Some(round);
+        self.on_time_streak = 0;
+
+        let fine = config.strike_fine.min(self.collateral_balance(config));
+        self.fines_paid = self.fines_paid
+            .checked_add(fine)
+            .ok_or(FundCycleError::MathOverflow)?;
+        vault.forfeit_collateral(fine)?;
+
+        if self.strikes >= config.max_strikes.max(1) {
+            // Mark inactive (punished)
+            self.active = false;
+            vault.forfeit_collateral(self.collateral_balance(config))?;
+            config.active_members = config.active_members.saturating_sub(1);
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use crate::state::fixtures::{ self, COLLATERAL, MONTHLY, START_TS };
+
+    #[test]
+    fn collateral_balance_is_zero_until_posted() {
+        let config = fixtures::config();
+        let mut member = fixtures::beneficiary(0);
+        member.collateral_paid = false;
+        assert_eq!(member.collateral_balance(&config), 0);
+        assert_eq!(member.net_position(&config), 0);
+
+        member.collateral_paid = true;
+        member.fines_paid = 2_500;
+        assert_eq!(member.collateral_balance(&config), COLLATERAL - 2_500);
+    }
+
+    #[test]
+    fn overdue_runs_on_the_round_clock() {
+        let mut config = fixtures::config();
+        let mut member = fixtures::beneficiary(1);
+        let due = config.round_due_ts();
+
+        assert!(!member.is_overdue(&config, due));
+        assert!(member.is_overdue(&config, due + 1));
+
+        // Paid this round
+        member.monthly_paid = true;
+        assert!(!member.is_overdue(&config, due + 1));
+
+        // A payment for an earlier round does not count
+        config.rounds_completed = 1;
+        config.round_start_ts = due;
+        assert!(member.is_overdue(&config, config.round_due_ts() + 1));
+
+        // No collateral posted
+        member.collateral_paid = false;
+        assert!(!member.is_overdue(&config, config.round_due_ts() + 1));
+
+        // Rotation finished
+        member.collateral_paid = true;
+        config.rounds_completed = config.total_rounds();
+        assert!(!member.is_overdue(&config, config.round_due_ts() + 1));
+    }
+
+    #[test]
+    fn recipient_is_not_overdue_for_the_round_it_drew() {
+        let mut config = fixtures::config();
+        config.payouts_per_round = 2;
+        let mut member = fixtures::beneficiary(0);
+        member.monthly_paid = true;
+        member.payout_received = true;
+        let due = config.round_due_ts();
+        assert!(!member.is_overdue(&config, due + 1));
+
+        // Next round the member is no longer a recipient and owes again
+        config.rounds_completed = 1;
+        config.current_index = 2;
+        config.round_start_ts = due;
+        assert!(member.is_overdue(&config, config.round_due_ts() + 1));
+    }
+
+    #[test]
+    fn held_contributions_follow_the_round() {
+        let mut config = fixtures::config();
+        let mut member = fixtures::beneficiary(1);
+        member.contributions = 3 * MONTHLY;
+        config.rounds_completed = 2;
+
+        // Unpaid this round: only the kept back share of earlier rounds
+        assert_eq!(member.held_contributions(&config).unwrap(), (0, 600));
+
+        // Paid this round and the pot is undrawn
+        member.monthly_paid = true;
+        member.paid_round = 2;
+        assert_eq!(member.held_contributions(&config).unwrap(), (MONTHLY, 400));
+
+        // Once a recipient draws, this round's payment is no longer the member's
+        config.round_withdrawals = 1;
+        assert_eq!(member.held_contributions(&config).unwrap(), (0, 400));
+    }
+
+    #[test]
+    fn dormancy_skips_time_spent_paused() {
+        let mut config = fixtures::config();
+        config.nominee_dormancy_days = 10;
+        let mut member = fixtures::beneficiary(0);
+        let dormant_at = START_TS + config.dormancy_secs();
+
+        assert!(!member.is_dormant(&config, dormant_at));
+        assert!(member.is_dormant(&config, dormant_at + 1));
+
+        // A day paused since the last payment pushes dormancy back a day
+        config.paused_secs = 86400;
+        assert!(!member.is_dormant(&config, dormant_at + 1));
+        assert!(member.is_dormant(&config, dormant_at + 86401));
+
+        // A later settings update counts from the pause total at that time
+        member.last_active_ts = START_TS + 1_000;
+        member.paused_secs_at_active = 86400;
+        assert!(!member.is_dormant(&config, dormant_at + 1_000));
+        assert!(member.is_dormant(&config, dormant_at + 1_001));
+
+        config.nominee_dormancy_days = 0;
+        assert!(!member.is_dormant(&config, i64::MAX / 2));
+    }
+
+    #[test]
+    fn nominee_is_paid_only_once_the_member_is_dormant() {
+        let mut config = fixtures::config();
+        config.nominee_dormancy_days = 10;
+        let mut member = fixtures::beneficiary(0);
+        let nominee = Pubkey::new_unique();
+        member.nominee = Some(nominee);
+        let dormant = START_TS + config.dormancy_secs() + 1;
+
+        assert_eq!(member.payout_recipient(&config, member.wallet, START_TS).unwrap(), member.wallet);
+        assert!(member.payout_recipient(&config, nominee, START_TS).is_err());
+        assert_eq!(member.payout_recipient(&config, nominee, dormant).unwrap(), nominee);
+        assert!(member.payout_recipient(&config, Pubkey::new_unique(), dormant).is_err());
+    }
+
+    #[test]
+    fn strike_fines_collateral_until_max_strikes() {
+        let mut config = fixtures::config();
+        config.max_strikes = 3;
+        config.strike_fine = 4_000;
+        let mut vault = fixtures::vault();
+        let mut member = fixtures::beneficiary(0);
+
+        assert!(!member.record_strike(&mut config, &mut vault).unwrap());
+        assert_eq!(member.strikes, 1);
+        assert_eq!(member.fines_paid, 4_000);
+        assert_eq!(vault.collateral_held, COLLATERAL - 4_000);
+        assert_eq!(vault.forfeited, 4_000);
+
+        // One strike per round
+        assert!(member.record_strike(&mut config, &mut vault).is_err());
+
+        config.rounds_completed = 1;
+        assert!(!member.record_strike(&mut config, &mut vault).unwrap());
+        assert_eq!(member.fines_paid, 8_000);
+
+        // Third strike deactivates and forfeits the rest, fine capped at the balance
+        config.rounds_completed = 2;
+        assert!(member.record_strike(&mut config, &mut vault).unwrap());
+        assert!(!member.active);
+        assert_eq!(member.fines_paid, COLLATERAL);
+        assert_eq!(member.collateral_balance(&config), 0);
+        assert_eq!(vault.collateral_held, 0);
+        assert_eq!(vault.forfeited, COLLATERAL);
+        assert_eq!(config.active_members, 3);
+
+        config.rounds_completed = 3;
+        assert!(member.record_strike(&mut config, &mut vault).is_err());
+    }
+
+    #[test]
+    fn strike_without_collateral_forfeits_nothing() {
+        let mut config = fixtures::config();
+        config.strike_fine = 4_000;
+        let mut vault = fixtures::vault();
+        let mut member = fixtures::beneficiary(0);
+        member.collateral_paid = false;
+
+        assert!(member.record_strike(&mut config, &mut vault).unwrap());
+        assert_eq!(member.fines_paid, 0);
+        assert_eq!(vault.collateral_held, COLLATERAL);
+        assert_eq!(vault.forfeited, 0);
+    }
 }
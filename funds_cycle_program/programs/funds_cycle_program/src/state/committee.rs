@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::error::FundCycleError;
+use crate::instructions::UpdateConfigArgs;
+
+pub const MAX_COMMITTEE_MEMBERS: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CommitteeAccount {
+    pub config: Pubkey,
+    #[max_len(MAX_COMMITTEE_MEMBERS)]
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,          // approvals needed to execute (M of N)
+    pub proposal_count: u64,
+    pub bump: u8,
+    pub authority_bump: u8,     // committee authority PDA, set as config.admin
+    pub epoch: u32,             // bumped on every membership change
+}
+
+impl CommitteeAccount {
+    pub fn member_index(&self, member: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|m| m == member)
+    }
+
+    /// Membership changes renumber the approval bitmap, so proposals
+    /// filed before one can no longer be approved or executed
+    pub fn add_member(&mut self, member: Pubkey) -> Result<()> {
+        require!(
+            self.member_index(&member).is_none() && self.members.len() < MAX_COMMITTEE_MEMBERS,
+            FundCycleError::InvalidCommittee
+        );
+        self.members.push(member);
+        self.bump_epoch()
+    }
+
+    pub fn remove_member(&mut self, member: Pubkey) -> Result<()> {
+        let index = self.member_index(&member).ok_or(FundCycleError::NotCommitteeMember)?;
+        require!(
+            self.members.len() > self.threshold as usize,
+            FundCycleError::InvalidCommittee
+        );
+        self.members.remove(index);
+        self.bump_epoch()
+    }
+
+    pub fn set_threshold(&mut self, threshold: u8) -> Result<()> {
+        require!(
+            threshold > 0 && threshold as usize <= self.members.len(),
+            FundCycleError::InvalidCommittee
+        );
+        self.threshold = threshold;
+        Ok(())
+    }
+
+    fn bump_epoch(&mut self) -> Result<()> {
+        self.epoch = self.epoch
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+/// Privileged actions the committee can execute once approved.
+/// New actions are appended so stored proposals keep decoding.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    AddBeneficiary { wallet: Pubkey },
+    Punish { beneficiary: Pubkey },
+    EnableClaiming,
+    Exit,
+    Pause,
+    Unpause,
+    RemoveBeneficiary { beneficiary: Pubkey },
+    FillVacancy { vacated: Pubkey, wallet: Pubkey },
+    UpdateConfig { args: UpdateConfigArgs },
+    ProposePunishment { beneficiary: Pubkey },
+    ProposeDissolution,
+    DeployReserve { amount: u64 },
+    RecallReserve { amount: u64 },
+    StakeReserve { amount: u64, vote_account: Pubkey },
+    DeactivateReserveStake,
+    /// Hands admin to a single wallet, which still has to `accept_admin`.
+    /// Once it does, the committee can no longer execute anything.
+    ProposeAdmin { new_admin: Pubkey },
+    /// Committee rotation - applied to the committee itself, no CPI
+    AddMember { member: Pubkey },
+    RemoveMember { member: Pubkey },
+    SetThreshold { threshold: u8 },
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalAccount {
+    pub committee: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    pub approvals: u16,         // bitmap over committee member indexes
+    pub executed: bool,
+    pub bump: u8,
+    pub epoch: u32,             // committee epoch it was filed in
+}
+
+impl ProposalAccount {
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn committee(size: usize, threshold: u8) -> CommitteeAccount {
+        CommitteeAccount {
+            config: Pubkey::new_unique(),
+            members: (0..size).map(|_| Pubkey::new_unique()).collect(),
+            threshold,
+            proposal_count: 0,
+            bump: 255,
+            authority_bump: 255,
+            epoch: 0,
+        }
+    }
+
+    #[test]
+    fn rotation_bumps_the_epoch() {
+        let mut committee = committee(3, 2);
+        let newcomer = Pubkey::new_unique();
+
+        committee.add_member(newcomer).unwrap();
+        assert_eq!(committee.member_index(&newcomer), Some(3));
+        assert_eq!(committee.epoch, 1);
+
+        let leaving = committee.members[0];
+        committee.remove_member(leaving).unwrap();
+        assert_eq!(committee.member_index(&leaving), None);
+        assert_eq!(committee.member_index(&newcomer), Some(2));
+        assert_eq!(committee.epoch, 2);
+
+        committee.set_threshold(3).unwrap();
+        assert_eq!(committee.epoch, 2);
+    }
+
+    #[test]
+    fn rotation_keeps_the_committee_valid() {
+        let mut committee = committee(2, 2);
+        let member = committee.members[0];
+
+        assert!(committee.add_member(member).is_err());
+        assert!(committee.remove_member(Pubkey::new_unique()).is_err());
+        // Would leave fewer members than the threshold
+        assert!(committee.remove_member(member).is_err());
+        assert!(committee.set_threshold(0).is_err());
+        assert!(committee.set_threshold(3).is_err());
+
+        committee.set_threshold(1).unwrap();
+        committee.remove_member(member).unwrap();
+        assert_eq!(committee.members.len(), 1);
+    }
+}
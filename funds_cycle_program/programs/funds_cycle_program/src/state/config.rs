@@ -1,9 +1,51 @@
 use anchor_lang::prelude::*;
+use crate::error::FundCycleError;
+use crate::utils::percent_of;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CycleStatus {
+    /// Slots are still being filled, no rounds have started
+    Enrollment,
+    /// All slots are taken and the round robin is running
+    Active,
+    /// Enrollment closed under-subscribed, members can reclaim collateral
+    Cancelled,
+    /// Members ratified a dissolution, waiting for net positions to be totalled
+    Dissolving,
+    /// Net positions totalled, members can claim their share of the vault
+    Dissolved,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum SkipPolicy {
+    /// A skipped round's pot stays in the vault as reserve
+    Reserve,
+    /// A skipped round's pot is added to the next recipient's payout
+    NextRecipient,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PunishMode {
+    /// Admin deactivates overdue members directly
+    Admin,
+    /// Admin files a proposal and active members vote on it
+    Vote,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CycleMode {
+    /// Each round's pot goes to one member in turn
+    Rotation,
+    /// No payout turns, everyone gets their contributions back at the end
+    SavingsClub,
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct ConfigAccount {
     pub admin: Pubkey,
+    pub creator: Pubkey, // config PDA seed, fixed even when the admin changes
+    pub pending_admin: Option<Pubkey>,
     pub collateral_amount: u64,
     pub monthly_payout: u64,
     pub payment_interval_days: u16, // e.g., 30 days
@@ -13,4 +55,258 @@ pub struct ConfigAccount {
     pub bump: u8,
     pub claimable: bool,
     pub claims_completed: u8,
+    pub status: CycleStatus,
+    pub enrollment_deadline_ts: i64, // 0 = no deadline
+    pub min_participants: u8,
+    pub rounds_completed: u8,
+    pub collaterals_posted: u8,
+    pub active_members: u8,
+    pub exit_forfeit_percent: u8, // share of collateral kept on early exit
+    pub exit_penalty_percent: u8, // share of contributions kept on early exit
+    pub round_start_ts: i64,
+    pub skip_policy: SkipPolicy,
+    pub rounds_skipped: u8,
+    pub carry_over: u64, // skipped pots owed to the next recipient
+    pub punish_mode: PunishMode,
+    pub vote_window_days: u16,
+    pub vote_majority_percent: u8, // share of eligible voters needed to punish
+    pub max_strikes: u8,           // strikes before deactivation (0 or 1 = first strike)
+    pub strike_fine: u64,          // deducted from collateral per strike
+    pub strike_decay_rounds: u8,   // on-time rounds that clear one strike (0 = never)
+    pub keeper_bounty: u64,        // paid to crank_punish callers from the defaulter's collateral
+    pub paused: bool,
+    pub paused_at: i64,
+    pub paused_secs: i64,          // total time spent paused, due dates are pushed back by it
+    pub dissolution_total: u64,    // sum of active members' net positions at dissolution
+    pub dissolution_pool: u64,     // vault balance shared out between them
+    pub residual_pool: u64,        // reserve and forfeits left for members once claiming opens
+    pub residual_weight: u64,      // active members' total contributions when claiming opened
+    pub round_collected: u64,      // contributions actually paid in for the current round
+    pub yield_program: Option<Pubkey>, // where the reserve can be deployed (see yield_interface)
+    pub mode: CycleMode,
+    pub payouts_per_round: u8,     // recipients paid each round (0 or 1 = one)
+    pub round_withdrawals: u8,     // recipients paid so far this round
+    pub round_share: u64,          // each recipient's share, fixed at the round's first withdrawal
+    pub vest_payouts: bool,        // payouts stream out over one interval instead of a lump sum
+    pub nominee_dormancy_days: u16, // inactivity before a nominee can claim (0 = nominees disabled)
+}
+
+impl ConfigAccount {
+    /// Closes enrollment with the slots filled so far. From here on
+    /// `current_index` is the payout pointer instead of the enrollment count.
+    pub fn start_cycle(&mut self, now: i64) {
+        self.max_beneficiaries = self.current_index;
+        self.active_members = self.current_index;
+        self.current_index = 0;
+        self.round_start_ts = now;
+        self.status = CycleStatus::Active;
+    }
+
+    /// Moves the payout pointer past this round's recipients (wrap around)
+    pub fn advance_round(&mut self, now: i64) -> Result<()> {
+        let next_index = self.current_index as u16 + self.payouts_per_round.max(1) as u16;
+        self.current_index = (next_index % self.max_beneficiaries as u16) as u8;
+        self.rounds_completed = self.rounds_completed
+            .checked_add(1)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.round_start_ts = now;
+        self.round_collected = 0;
+        self.round_withdrawals = 0;
+        self.round_share = 0;
+        Ok(())
+    }
+
+    /// Rounds in a full rotation: slots divided by payouts per round, rounded up
+    pub fn total_rounds(&self) -> u8 {
+        self.max_beneficiaries.div_ceil(self.payouts_per_round.max(1))
+    }
+
+    pub fn rotation_complete(&self) -> bool {
+        self.rounds_completed >= self.total_rounds()
+    }
+
+    /// Recipients in the current round - the last round may be short
+    pub fn recipients_in_round(&self) -> u8 {
+        self.payouts_per_round
+            .max(1)
+            .min(self.max_beneficiaries.saturating_sub(self.current_index))
+    }
+
+    /// Whether the slot is among this round's recipients
+    pub fn is_recipient(&self, index: u8) -> bool {
+        index >= self.current_index
+            && (index - self.current_index) < self.recipients_in_round()
+    }
+
+    /// Round pot: round_collected * withdraw_percent / 100. Only what was
+    /// actually contributed this round is paid out, never collateral.
+    pub fn round_pot(&self) -> Result<u64> {
+        percent_of(self.round_collected, self.withdraw_percent)
+    }
+
+    pub fn interval_secs(&self) -> i64 {
+        self.payment_interval_days as i64 * 86400 // days → seconds
+    }
+
+    /// Contributions for the current round are on time until one interval in
+    pub fn round_due_ts(&self) -> i64 {
+        self.round_start_ts + self.interval_secs()
+    }
+
+    pub fn vote_window_secs(&self) -> i64 {
+        self.vote_window_days as i64 * 86400
+    }
+
+    pub fn dormancy_secs(&self) -> i64 {
+        self.nominee_dormancy_days as i64 * 86400
+    }
+
+    /// Contributions fall due one interval into the round, and the
+    /// recipient then has one more interval to withdraw
+    pub fn payout_window_end(&self) -> i64 {
+        self.round_start_ts + 2 * self.interval_secs()
+    }
+
+    /// Member's cut of the residual pool, in proportion to contributions.
+    /// With no contributions to weigh by, it is split evenly.
+    pub fn residual_share(&self, contributions: u64) -> u64 {
+        if self.residual_weight == 0 {
+            return self.residual_pool / self.active_members.max(1) as u64;
+        }
+        ((contributions as u128) * (self.residual_pool as u128)
+            / (self.residual_weight as u128)) as u64
+    }
+
+    pub fn enrollment_deadline_passed(&self, now: i64) -> bool {
+        self.enrollment_deadline_ts != 0 && now > self.enrollment_deadline_ts
+    }
+
+    /// Fewest members needed for the cycle to run (at least one)
+    pub fn min_required(&self) -> u8 {
+        self.min_participants.max(1)
+    }
+
+    /// Time spent paused since `paused_secs` read `paused_then`,
+    /// counting a pause that is still running
+    pub fn paused_secs_since(&self, paused_then: i64, now: i64) -> i64 {
+        let running = if self.paused { now - self.paused_at } else { 0 };
+        self.paused_secs - paused_then + running
+    }
+
+    /// Ends a pause and returns how long it lasted. A running deadline
+    /// only moves back by the part of the pause it was running for.
+    pub fn resume(&mut self, now: i64) -> i64 {
+        let paused_for = now - self.paused_at;
+        self.paused_secs += paused_for;
+        match self.status {
+            CycleStatus::Active => {
+                self.round_start_ts += now - self.round_start_ts.max(self.paused_at)
+            }
+            CycleStatus::Enrollment if self.enrollment_deadline_ts != 0 => {
+                self.enrollment_deadline_ts += paused_for
+            }
+            _ => {}
+        }
+        self.paused = false;
+        self.paused_at = 0;
+        paused_for
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::fixtures::{ self, START_TS };
+
+    #[test]
+    fn recipients_cover_the_round_and_a_short_last_round() {
+        let mut config = fixtures::config();
+        config.max_beneficiaries = 5;
+        config.payouts_per_round = 2;
+        assert_eq!(config.total_rounds(), 3);
+
+        assert_eq!(config.recipients_in_round(), 2);
+        assert!(config.is_recipient(0) && config.is_recipient(1));
+        assert!(!config.is_recipient(2));
+
+        config.advance_round(START_TS + 1).unwrap();
+        assert_eq!(config.current_index, 2);
+        assert!(!config.is_recipient(1));
+        assert!(config.is_recipient(2) && config.is_recipient(3));
+
+        // Last round only has slot 4 left
+        config.advance_round(START_TS + 2).unwrap();
+        assert_eq!(config.current_index, 4);
+        assert_eq!(config.recipients_in_round(), 1);
+        assert!(config.is_recipient(4));
+        assert!(!config.is_recipient(0));
+
+        config.advance_round(START_TS + 3).unwrap();
+        assert!(config.rotation_complete());
+    }
+
+    #[test]
+    fn one_recipient_when_payouts_per_round_unset() {
+        let mut config = fixtures::config();
+        config.payouts_per_round = 0;
+        assert_eq!(config.recipients_in_round(), 1);
+        assert_eq!(config.total_rounds(), 4);
+        assert!(config.is_recipient(0) && !config.is_recipient(1));
+    }
+
+    #[test]
+    fn residual_share_follows_contributions() {
+        let mut config = fixtures::config();
+        config.residual_pool = 1_000;
+        config.residual_weight = 3_000;
+
+        assert_eq!(config.residual_share(1_500), 500);
+        assert_eq!(config.residual_share(1_000), 333);
+        assert_eq!(config.residual_share(0), 0);
+    }
+
+    #[test]
+    fn residual_share_splits_evenly_without_contributions() {
+        let mut config = fixtures::config();
+        config.residual_pool = 1_000;
+        config.residual_weight = 0;
+        assert_eq!(config.residual_share(0), 250);
+
+        config.residual_pool = 0;
+        assert_eq!(config.residual_share(0), 0);
+    }
+
+    #[test]
+    fn resume_shifts_the_round_by_the_pause() {
+        let mut config = fixtures::config();
+        config.paused = true;
+        config.paused_at = START_TS + 100;
+
+        assert_eq!(config.resume(START_TS + 400), 300);
+        assert_eq!(config.round_start_ts, START_TS + 300);
+        assert_eq!(config.paused_secs, 300);
+        assert!(!config.paused);
+    }
+
+    #[test]
+    fn resume_only_shifts_a_round_for_the_time_it_ran() {
+        // The round started partway through the pause
+        let mut config = fixtures::config();
+        config.paused = true;
+        config.paused_at = START_TS - 200;
+
+        assert_eq!(config.resume(START_TS + 100), 300);
+        assert_eq!(config.round_start_ts, START_TS + 100);
+        assert_eq!(config.paused_secs, 300);
+    }
+
+    #[test]
+    fn paused_secs_since_counts_a_running_pause() {
+        let mut config = fixtures::config();
+        config.paused_secs = 500;
+        assert_eq!(config.paused_secs_since(200, START_TS), 300);
+
+        config.paused = true;
+        config.paused_at = START_TS;
+        assert_eq!(config.paused_secs_since(200, START_TS + 50), 350);
+    }
 }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::ConfigAccount;
+
+/// Admin's proposal to wind the cycle up early. Members ratify it
+/// with `VoteRecord`s, a simple majority of active members carries it
+/// before `closes_ts` (plus any time paused); past that it can only be closed.
+#[account]
+#[derive(InitSpace)]
+pub struct DissolutionProposal {
+    pub config: Pubkey,
+    pub proposer: Pubkey,        // paid the rent when it was filed, gets it back
+    pub opened_ts: i64,
+    pub closes_ts: i64,          // last moment it can be ratified, before pauses
+    pub votes_for: u8,
+    pub bump: u8,
+    pub paused_secs_at_open: i64, // config.paused_secs when it was filed
+}
+
+impl DissolutionProposal {
+    /// The deadline moves back by any time the cycle spent paused
+    pub fn voting_open(&self, config: &ConfigAccount, now: i64) -> bool {
+        now <= self.closes_ts + config.paused_secs_since(self.paused_secs_at_open, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::fixtures::{ self, START_TS };
+
+    fn proposal() -> DissolutionProposal {
+        DissolutionProposal {
+            config: Pubkey::new_unique(),
+            proposer: Pubkey::new_unique(),
+            opened_ts: START_TS,
+            closes_ts: START_TS + 86400,
+            votes_for: 0,
+            bump: 255,
+            paused_secs_at_open: 0,
+        }
+    }
+
+    #[test]
+    fn voting_closes_after_the_deadline() {
+        let config = fixtures::config();
+        let proposal = proposal();
+
+        assert!(proposal.voting_open(&config, START_TS));
+        assert!(proposal.voting_open(&config, START_TS + 86400));
+        assert!(!proposal.voting_open(&config, START_TS + 86401));
+    }
+
+    #[test]
+    fn voting_stays_open_for_time_spent_paused() {
+        let mut config = fixtures::config();
+        let proposal = proposal();
+
+        // Paused an hour before the deadline and still paused past it
+        config.paused = true;
+        config.paused_at = START_TS + 82800;
+        assert!(proposal.voting_open(&config, START_TS + 90000));
+
+        // Unpaused after two hours: the deadline moved back by both
+        config.paused = false;
+        config.paused_secs = 7200;
+        assert!(proposal.voting_open(&config, START_TS + 86400 + 7200));
+        assert!(!proposal.voting_open(&config, START_TS + 86401 + 7200));
+    }
+}
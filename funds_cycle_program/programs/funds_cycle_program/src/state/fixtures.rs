@@ -0,0 +1,107 @@
+//! Account values for the state unit tests: an active four-member
+//! rotation on a 30 day interval, one member with collateral posted, and
+//! a vault holding that collateral.
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+pub const COLLATERAL: u64 = 10_000;
+pub const MONTHLY: u64 = 1_000;
+pub const START_TS: i64 = 1_700_000_000;
+
+pub fn config() -> ConfigAccount {
+    ConfigAccount {
+        admin: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        pending_admin: None,
+        collateral_amount: COLLATERAL,
+        monthly_payout: MONTHLY,
+        payment_interval_days: 30,
+        withdraw_percent: 80,
+        max_beneficiaries: 4,
+        current_index: 0,
+        bump: 255,
+        claimable: false,
+        claims_completed: 0,
+        status: CycleStatus::Active,
+        enrollment_deadline_ts: 0,
+        min_participants: 0,
+        rounds_completed: 0,
+        collaterals_posted: 4,
+        active_members: 4,
+        exit_forfeit_percent: 0,
+        exit_penalty_percent: 0,
+        round_start_ts: START_TS,
+        skip_policy: SkipPolicy::Reserve,
+        rounds_skipped: 0,
+        carry_over: 0,
+        punish_mode: PunishMode::Admin,
+        vote_window_days: 0,
+        vote_majority_percent: 0,
+        max_strikes: 1,
+        strike_fine: 0,
+        strike_decay_rounds: 0,
+        keeper_bounty: 0,
+        paused: false,
+        paused_at: 0,
+        paused_secs: 0,
+        dissolution_total: 0,
+        dissolution_pool: 0,
+        residual_pool: 0,
+        residual_weight: 0,
+        round_collected: 0,
+        yield_program: None,
+        mode: CycleMode::Rotation,
+        payouts_per_round: 1,
+        round_withdrawals: 0,
+        round_share: 0,
+        vest_payouts: false,
+        nominee_dormancy_days: 0,
+    }
+}
+
+pub fn beneficiary(index: u8) -> BeneficiaryAccount {
+    BeneficiaryAccount {
+        config: Pubkey::new_unique(),
+        wallet: Pubkey::new_unique(),
+        bump: 255,
+        index,
+        collateral_paid: true,
+        monthly_paid: false,
+        last_payment_ts: START_TS,
+        active: true,
+        collateral_claimed: false,
+        payer: Pubkey::new_unique(),
+        contributions: 0,
+        payout_received: false,
+        exited: false,
+        replaced: false,
+        skipped: false,
+        paid_round: 0,
+        strikes: 0,
+        last_strike_round: None,
+        on_time_streak: 0,
+        fines_paid: 0,
+        paused_secs_at_payment: 0,
+        payouts_received: 0,
+        payout_destination: None,
+        nominee: None,
+        last_active_ts: 0,
+        paused_secs_at_active: 0,
+    }
+}
+
+pub fn vault() -> VaultAccount {
+    VaultAccount {
+        config: Pubkey::new_unique(),
+        bump: 255,
+        collateral_held: COLLATERAL,
+        contribution_pool: 0,
+        reserve: 0,
+        fees_owed: 0,
+        forfeited: 0,
+        reserve_deployed: 0,
+        yield_accrued: 0,
+        reserve_staked: 0,
+        vesting: 0,
+    }
+}
@@ -6,3 +6,18 @@ pub use beneficiaries::*;
 
 pub mod vault;
 pub use vault::*;
+
+pub mod committee;
+pub use committee::*;
+
+pub mod punishment;
+pub use punishment::*;
+
+pub mod dissolution;
+pub use dissolution::*;
+
+pub mod stream;
+pub use stream::*;
+
+#[cfg(test)]
+pub mod fixtures;
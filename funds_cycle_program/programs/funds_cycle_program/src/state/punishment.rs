@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ConfigAccount;
+
+/// Pending vote on deactivating an overdue member (`PunishMode::Vote`)
+#[account]
+#[derive(InitSpace)]
+pub struct PunishmentProposal {
+    pub config: Pubkey,
+    pub beneficiary: Pubkey,     // accused beneficiary account
+    pub proposer: Pubkey,        // paid the rent when it was filed, gets it back
+    pub opened_ts: i64,
+    pub eligible_voters: u8,     // active members other than the accused at filing
+    pub votes_for: u8,
+    pub votes_against: u8,
+    pub bump: u8,
+    pub paused_secs_at_open: i64, // config.paused_secs when it was filed
+}
+
+impl PunishmentProposal {
+    /// Votes run for `vote_window_days`, not counting time spent paused
+    pub fn voting_open(&self, config: &ConfigAccount, now: i64) -> bool {
+        now <= self.opened_ts + config.vote_window_secs()
+            + config.paused_secs_since(self.paused_secs_at_open, now)
+    }
+}
+
+/// One per voter per proposal - stops double voting
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub bump: u8,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+/// Payout released linearly over one payment interval (`vest_payouts`).
+/// The lamports stay in the vault until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct StreamAccount {
+    pub config: Pubkey,
+    pub beneficiary: Pubkey,
+    pub wallet: Pubkey,          // member the stream belongs to, gets the rent back
+    pub destination: Pubkey,     // where vested lamports are sent
+    pub total: u64,
+    pub claimed: u64,
+    pub start_ts: i64,
+    pub duration_secs: i64,
+    pub bump: u8,
+}
+
+impl StreamAccount {
+    /// Amount unlocked by `now`
+    pub fn vested(&self, now: i64) -> u64 {
+        if self.duration_secs <= 0 || now >= self.start_ts + self.duration_secs {
+            return self.total;
+        }
+        let elapsed = (now - self.start_ts).max(0);
+        ((self.total as u128) * (elapsed as u128) / (self.duration_secs as u128)) as u64
+    }
+
+    /// Fully claimed - the account can be closed
+    pub fn finished(&self) -> bool {
+        self.claimed >= self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(total: u64, duration_secs: i64) -> StreamAccount {
+        StreamAccount {
+            config: Pubkey::new_unique(),
+            beneficiary: Pubkey::new_unique(),
+            wallet: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            total,
+            claimed: 0,
+            start_ts: 1_000,
+            duration_secs,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn vests_linearly_over_the_duration() {
+        let stream = stream(9_000, 90);
+        assert_eq!(stream.vested(1_000), 0);
+        assert_eq!(stream.vested(1_030), 3_000);
+        assert_eq!(stream.vested(1_089), 8_900);
+        assert_eq!(stream.vested(1_090), 9_000);
+        assert_eq!(stream.vested(5_000), 9_000);
+    }
+
+    #[test]
+    fn nothing_vests_before_the_start() {
+        assert_eq!(stream(9_000, 90).vested(500), 0);
+    }
+
+    #[test]
+    fn zero_duration_vests_at_once() {
+        assert_eq!(stream(9_000, 0).vested(0), 9_000);
+        assert_eq!(stream(9_000, -5).vested(1_000), 9_000);
+    }
+
+    #[test]
+    fn rounds_down_until_the_end() {
+        let stream = stream(10, 3);
+        assert_eq!(stream.vested(1_001), 3);
+        assert_eq!(stream.vested(1_002), 6);
+        assert_eq!(stream.vested(1_003), 10);
+    }
+
+    #[test]
+    fn finished_once_everything_is_claimed() {
+        let mut stream = stream(9_000, 90);
+        assert!(!stream.finished());
+        stream.claimed = 9_000;
+        assert!(stream.finished());
+    }
+}
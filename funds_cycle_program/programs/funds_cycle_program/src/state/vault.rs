@@ -1,10 +1,186 @@
 use anchor_lang::prelude::*;
+use crate::error::FundCycleError;
 
+/// Holds every lamport of the cycle. Balances are tracked per purpose so
+/// each outflow can only draw on the bucket it is meant to.
 #[account]
 #[derive(InitSpace)]
 pub struct VaultAccount {
     pub config: Pubkey,
     pub bump: u8,
+    pub collateral_held: u64,    // collateral posted and not yet returned or forfeited
+    pub contribution_pool: u64,  // current round's contributions plus any carry-over
+    pub reserve: u64,            // share of each round kept back, plus catch-up payments
+    pub fees_owed: u64,          // keeper bounties accrued and not yet paid out
+    pub forfeited: u64,          // strike fines and forfeited collateral
+    pub reserve_deployed: u64,   // reserve principal out in the yield program
+    pub yield_accrued: u64,      // earned on deployed or staked reserve, shared out at settlement
+    pub reserve_staked: u64,     // reserve delegated through the reserve stake account
+    pub vesting: u64,            // payouts owed to open streams, not yet claimed
 }
 
+impl VaultAccount {
+    pub fn credit_collateral(&mut self, amount: u64) -> Result<()> {
+        self.collateral_held = self.collateral_held
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
 
+    pub fn debit_collateral(&mut self, amount: u64) -> Result<()> {
+        self.collateral_held = self.collateral_held
+            .checked_sub(amount)
+            .ok_or(FundCycleError::InsufficientCollateralHeld)?;
+        Ok(())
+    }
+
+    /// Moves collateral the member no longer has a claim on to `forfeited`
+    pub fn forfeit_collateral(&mut self, amount: u64) -> Result<()> {
+        self.debit_collateral(amount)?;
+        self.forfeited = self.forfeited
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn debit_forfeited(&mut self, amount: u64) -> Result<()> {
+        self.forfeited = self.forfeited
+            .checked_sub(amount)
+            .ok_or(FundCycleError::InsufficientForfeited)?;
+        Ok(())
+    }
+
+    pub fn credit_contributions(&mut self, amount: u64) -> Result<()> {
+        self.contribution_pool = self.contribution_pool
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn debit_contributions(&mut self, amount: u64) -> Result<()> {
+        self.contribution_pool = self.contribution_pool
+            .checked_sub(amount)
+            .ok_or(FundCycleError::InsufficientContributionPool)?;
+        Ok(())
+    }
+
+    /// Keeps part of the contribution pool back as reserve
+    pub fn retain_contributions(&mut self, amount: u64) -> Result<()> {
+        self.debit_contributions(amount)?;
+        self.credit_reserve(amount)
+    }
+
+    pub fn credit_reserve(&mut self, amount: u64) -> Result<()> {
+        self.reserve = self.reserve
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn debit_reserve(&mut self, amount: u64) -> Result<()> {
+        self.reserve = self.reserve
+            .checked_sub(amount)
+            .ok_or(FundCycleError::InsufficientReserve)?;
+        Ok(())
+    }
+
+    /// Moves part of the forfeits to `fees_owed` as a keeper's bounty
+    pub fn accrue_fee(&mut self, amount: u64) -> Result<()> {
+        self.debit_forfeited(amount)?;
+        self.fees_owed = self.fees_owed
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn debit_fees(&mut self, amount: u64) -> Result<()> {
+        self.fees_owed = self.fees_owed
+            .checked_sub(amount)
+            .ok_or(FundCycleError::InsufficientFeesOwed)?;
+        Ok(())
+    }
+
+    pub fn credit_vesting(&mut self, amount: u64) -> Result<()> {
+        self.vesting = self.vesting
+            .checked_add(amount)
+            .ok_or(FundCycleError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn debit_vesting(&mut self, amount: u64) -> Result<()> {
+        self.vesting = self.vesting
+            .checked_sub(amount)
+            .ok_or(FundCycleError::InsufficientVesting)?;
+        Ok(())
+    }
+
+    /// Folds the contribution pool (less `keep_contributions` still owed
+    /// back to members), forfeits and yield into the reserve once no more
+    /// rounds will be paid. Fees owed stay out of it. Returns the new reserve.
+    pub fn fold_into_reserve(&mut self, keep_contributions: u64) -> Result<u64> {
+        require!(
+            self.reserve_deployed == 0 && self.reserve_staked == 0,
+            FundCycleError::ReserveDeployed
+        );
+        let surplus_contributions = self.contribution_pool
+            .checked_sub(keep_contributions)
+            .ok_or(FundCycleError::InsufficientContributionPool)?;
+        let folded = surplus_contributions
+            .checked_add(self.forfeited)
+            .ok_or(FundCycleError::MathOverflow)?
+            .checked_add(self.yield_accrued)
+            .ok_or(FundCycleError::MathOverflow)?;
+        self.credit_reserve(folded)?;
+        self.contribution_pool = keep_contributions;
+        self.forfeited = 0;
+        self.yield_accrued = 0;
+        Ok(self.reserve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::state::fixtures;
+
+    #[test]
+    fn fold_into_reserve_keeps_owed_contributions() {
+        let mut vault = fixtures::vault();
+        vault.contribution_pool = 4_000;
+        vault.reserve = 800;
+        vault.forfeited = 300;
+        vault.yield_accrued = 50;
+
+        assert_eq!(vault.fold_into_reserve(1_000).unwrap(), 800 + 3_000 + 300 + 50);
+        assert_eq!(vault.contribution_pool, 1_000);
+        assert_eq!(vault.forfeited, 0);
+        assert_eq!(vault.yield_accrued, 0);
+    }
+
+    #[test]
+    fn fees_accrue_from_forfeits_and_stay_out_of_the_reserve() {
+        let mut vault = fixtures::vault();
+        vault.forfeited = 300;
+
+        vault.accrue_fee(100).unwrap();
+        assert_eq!(vault.forfeited, 200);
+        assert_eq!(vault.fees_owed, 100);
+        assert!(vault.accrue_fee(201).is_err());
+
+        assert_eq!(vault.fold_into_reserve(0).unwrap(), 200);
+        assert_eq!(vault.fees_owed, 100);
+
+        vault.debit_fees(100).unwrap();
+        assert!(vault.debit_fees(1).is_err());
+    }
+
+    #[test]
+    fn fold_into_reserve_requires_recalled_reserve() {
+        let mut vault = fixtures::vault();
+        vault.reserve_deployed = 100;
+        assert!(vault.fold_into_reserve(0).is_err());
+
+        vault.reserve_deployed = 0;
+        vault.reserve_staked = 100;
+        assert!(vault.fold_into_reserve(0).is_err());
+    }
+}
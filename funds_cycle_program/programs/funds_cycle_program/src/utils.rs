@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::error::FundCycleError;
+use crate::state::{ VaultAccount, VoteRecord };
+
+/// `amount * percent / 100` with overflow protection
+pub fn percent_of(amount: u64, percent: u8) -> Result<u64> {
+    let value = amount
+        .checked_mul(percent as u64)
+        .ok_or(FundCycleError::MathOverflow)?
+        .checked_div(100)
+        .ok_or(FundCycleError::MathOverflow)?;
+    Ok(value)
+}
+
+/// Lamports the vault can pay out without dropping below rent exemption
+pub fn vault_spendable(vault: &Account<VaultAccount>) -> Result<u64> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(8 + VaultAccount::INIT_SPACE);
+    Ok(vault.to_account_info().lamports().saturating_sub(rent_exempt_minimum))
+}
+
+/// Every outflow but `exit` must leave the vault rent exempt
+pub fn assert_rent_safe_outflow(vault: &Account<VaultAccount>, amount: u64) -> Result<()> {
+    require!(amount <= vault_spendable(vault)?, FundCycleError::VaultBelowRentExempt);
+    Ok(())
+}
+
+/// Pays `amount` out of the vault. The vault is owned by this program and
+/// carries data, so the system program cannot debit it - lamports are moved
+/// directly instead. Every outflow but `exit` must leave it rent exempt.
+pub fn pay_from_vault<'info>(
+    vault: &Account<'info, VaultAccount>,
+    to: &AccountInfo<'info>,
+    amount: u64
+) -> Result<()> {
+    assert_rent_safe_outflow(vault, amount)?;
+    vault.sub_lamports(amount)?;
+    to.add_lamports(amount)?;
+    Ok(())
+}
+
+/// Solvency invariant: lamports above the vault's rent-exempt minimum must
+/// cover every outstanding collateral, the reserve and unclaimed streams
+pub fn assert_vault_solvent(vault: &Account<VaultAccount>) -> Result<()> {
+    let available = vault_spendable(vault)?;
+    let owed = vault.collateral_held
+        .checked_add(vault.reserve)
+        .ok_or(FundCycleError::MathOverflow)?
+        .checked_add(vault.vesting)
+        .ok_or(FundCycleError::MathOverflow)?
+        .checked_add(vault.fees_owed)
+        .ok_or(FundCycleError::MathOverflow)?;
+    require!(available >= owed, FundCycleError::VaultInsolvent);
+    Ok(())
+}
+
+/// Closes every `VoteRecord` cast on `proposal`, refunding each voter's
+/// rent. `accounts` holds `(vote_record, voter)` pairs, one per vote, so
+/// no record outlives its proposal and blocks a later vote at the same PDA.
+pub fn close_vote_records<'info>(
+    proposal: Pubkey,
+    accounts: &'info [AccountInfo<'info>],
+    votes: usize
+) -> Result<()> {
+    require!(accounts.len() == votes * 2, FundCycleError::IncompleteVoteRecords);
+
+    for pair in accounts.chunks(2) {
+        // A record closed earlier in the loop no longer deserializes
+        let record = Account::<VoteRecord>::try_from(&pair[0])?;
+        require_keys_eq!(record.proposal, proposal, FundCycleError::IncompleteVoteRecords);
+        require_keys_eq!(pair[1].key(), record.voter, FundCycleError::InvalidRentPayer);
+        record.close(pair[1].clone())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Interface a yield program must expose for the reserve to be deployed
+//! into it. Any program - a lending or staking adapter, or a local mock
+//! in tests - can stand in as long as it implements these two
+//! instructions.
+//!
+//! Both take the accounts `[depositor (signer, writable), position
+//! (writable), system_program]`, where the depositor is the cycle's vault
+//! PDA and the position is whatever account the yield program keeps the
+//! deposit in. Data is an Anchor-style discriminator followed by a
+//! little-endian `u64` amount.
+//!
+//! - `yield_deposit(amount)` records a deposit. The vault is owned by the
+//!   cycle program and carries data, so no other program can debit it:
+//!   the cycle program moves `amount` lamports into the position itself
+//!   before the call, and the yield program only books them.
+//! - `yield_withdraw(amount)` returns `amount` lamports of principal to
+//!   the depositor, plus any yield earned on it. The position belongs to
+//!   the yield program, so it can debit it directly.
+//!
+//! `programs/mock_yield` implements this interface for the tests.
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{ AccountMeta, Instruction };
+
+/// `sha256("global:yield_deposit")[..8]`
+pub const YIELD_DEPOSIT_DISCRIMINATOR: [u8; 8] = [197, 128, 119, 222, 188, 102, 165, 63];
+/// `sha256("global:yield_withdraw")[..8]`
+pub const YIELD_WITHDRAW_DISCRIMINATOR: [u8; 8] = [225, 135, 226, 199, 89, 96, 95, 196];
+
+pub fn yield_deposit_ix(
+    yield_program: Pubkey,
+    depositor: Pubkey,
+    position: Pubkey,
+    amount: u64
+) -> Instruction {
+    build_ix(YIELD_DEPOSIT_DISCRIMINATOR, yield_program, depositor, position, amount)
+}
+
+pub fn yield_withdraw_ix(
+    yield_program: Pubkey,
+    depositor: Pubkey,
+    position: Pubkey,
+    amount: u64
+) -> Instruction {
+    build_ix(YIELD_WITHDRAW_DISCRIMINATOR, yield_program, depositor, position, amount)
+}
+
+fn build_ix(
+    discriminator: [u8; 8],
+    yield_program: Pubkey,
+    depositor: Pubkey,
+    position: Pubkey,
+    amount: u64
+) -> Instruction {
+    let mut data = discriminator.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    Instruction {
+        program_id: yield_program,
+        accounts: vec![
+            AccountMeta::new(depositor, true),
+            AccountMeta::new(position, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+        ],
+        data,
+    }
+}
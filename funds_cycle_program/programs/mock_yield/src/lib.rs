@@ -0,0 +1,124 @@
+#![allow(deprecated, unexpected_cfgs)]
+//! Local stand-in for a yield program, used by the tests. Implements the
+//! two instructions `funds_cycle_program::yield_interface` expects.
+//!
+//! Yield is simulated by sending lamports straight into a position: on
+//! withdraw, whatever the position holds above its principal is paid out
+//! as yield, in proportion to the principal withdrawn.
+use anchor_lang::prelude::*;
+
+declare_id!("DcHoVG1EJH5F8ZkzNCkD3fhGaEEFsnNkMb5fH8zbVVE2");
+
+#[program]
+pub mod mock_yield {
+    use super::*;
+
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        ctx.accounts.position.set_inner(Position {
+            depositor: ctx.accounts.depositor.key(),
+            principal: 0,
+            bump: ctx.bumps.position,
+        });
+        Ok(())
+    }
+
+    /// The depositor has already moved `amount` lamports into the position
+    pub fn yield_deposit(ctx: Context<MovePosition>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.principal = position.principal
+            .checked_add(amount)
+            .ok_or(MockYieldError::MathOverflow)?;
+
+        require!(
+            held(position)? >= position.principal,
+            MockYieldError::DepositNotReceived
+        );
+
+        msg!("Deposit of {} lamports recorded | Principal: {}", amount, position.principal);
+        Ok(())
+    }
+
+    /// Returns `amount` of principal to the depositor, plus its share of
+    /// whatever the position holds above principal
+    pub fn yield_withdraw(ctx: Context<MovePosition>, amount: u64) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        require!(
+            amount > 0 && amount <= position.principal,
+            MockYieldError::InvalidAmount
+        );
+
+        let surplus = held(position)?.saturating_sub(position.principal);
+        let earned = ((surplus as u128) * (amount as u128) / (position.principal as u128)) as u64;
+        let payout = amount
+            .checked_add(earned)
+            .ok_or(MockYieldError::MathOverflow)?;
+
+        position.principal -= amount;
+        position.sub_lamports(payout)?;
+        ctx.accounts.depositor.add_lamports(payout)?;
+
+        msg!("Returned {} lamports of principal and {} of yield", amount, earned);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Account the position is opened for, e.g. a cycle vault
+    pub depositor: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"position", depositor.key().as_ref()],
+        bump,
+        space = 8 + Position::INIT_SPACE
+    )]
+    pub position: Account<'info, Position>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account layout required by `yield_interface`
+#[derive(Accounts)]
+pub struct MovePosition<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"position", depositor.key().as_ref()],
+        bump = position.bump,
+        has_one = depositor
+    )]
+    pub position: Account<'info, Position>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Position {
+    pub depositor: Pubkey,
+    pub principal: u64,
+    pub bump: u8,
+}
+
+/// Lamports the position holds above its own rent-exempt minimum
+fn held(position: &Account<Position>) -> Result<u64> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(8 + Position::INIT_SPACE);
+    Ok(position.to_account_info().lamports().saturating_sub(rent_exempt_minimum))
+}
+
+#[error_code]
+pub enum MockYieldError {
+    #[msg("Deposit lamports were not moved into the position")]
+    DepositNotReceived,
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Math overflow occurred")]
+    MathOverflow,
+}
@@ -0,0 +1,99 @@
+// user-048: advancing multi-recipient rounds
+import { describe, it } from "node:test";
+import assert from "assert";
+import {
+  advanceRound,
+  createCycle,
+  depositMonthly,
+  earlyExit,
+  expectError,
+  fetchBeneficiary,
+  fetchConfig,
+  fetchVault,
+  payRound,
+  program,
+  sleep,
+  withdraw,
+} from "./utils/cycle.ts";
+
+describe("advance round", () => {
+  it("skips a round whose slots are all vacant straight away", async () => {
+    const cycle = await createCycle({ members: 4, terms: { payoutsPerRound: 2 } });
+    await earlyExit(cycle, 0);
+    await earlyExit(cycle, 1);
+
+    await advanceRound(cycle, [cycle.beneficiaries[0], cycle.beneficiaries[1]]);
+
+    const config = await fetchConfig(cycle);
+    assert.strictEqual(config.roundsCompleted, 1);
+    assert.strictEqual(config.currentIndex, 2);
+    assert.strictEqual((await fetchBeneficiary(cycle, 0)).skipped, true);
+    assert.strictEqual((await fetchBeneficiary(cycle, 1)).skipped, true);
+  });
+
+  it("waits out the window while any recipient could still withdraw", async () => {
+    // A day-long interval keeps the payout window open for the test
+    const cycle = await createCycle({ members: 4, intervalDays: 1, terms: { payoutsPerRound: 2 } });
+    await earlyExit(cycle, 0);
+
+    await expectError(
+      advanceRound(cycle, [cycle.beneficiaries[0], cycle.beneficiaries[1]]),
+      "PayoutWindowOpen"
+    );
+  });
+
+  it("requires every recipient of the round, in slot order", async () => {
+    const cycle = await createCycle({ members: 4, terms: { payoutsPerRound: 2 } });
+    await earlyExit(cycle, 0);
+    await earlyExit(cycle, 1);
+
+    await expectError(advanceRound(cycle, [cycle.beneficiaries[0]]), "IncompleteBeneficiarySet");
+    await expectError(
+      advanceRound(cycle, [cycle.beneficiaries[1], cycle.beneficiaries[0]]),
+      "IncompleteBeneficiarySet"
+    );
+  });
+
+  it("marks only the recipients who did not withdraw as skipped", async () => {
+    const cycle = await createCycle({ members: 4, terms: { payoutsPerRound: 2 } });
+    await payRound(cycle);
+    await withdraw(cycle, 0);
+    // Member 1 never withdraws; with a zero-day interval the window is already over
+    await new Promise(resolve => setTimeout(resolve, 1_500));
+
+    const reserveBefore = (await fetchVault(cycle)).reserve.toNumber();
+    await advanceRound(cycle, [cycle.beneficiaries[0], cycle.beneficiaries[1]]);
+
+    assert.strictEqual((await fetchBeneficiary(cycle, 0)).skipped, false);
+    assert.strictEqual((await fetchBeneficiary(cycle, 1)).skipped, true);
+    // Member 1's unpaid share stays in the vault as reserve
+    assert.ok((await fetchVault(cycle)).reserve.toNumber() > reserveBefore);
+  });
+
+  it("does not treat a recipient who already withdrew as overdue", async () => {
+    const cycle = await createCycle({ members: 4, terms: { payoutsPerRound: 2 } });
+    await payRound(cycle);
+    await withdraw(cycle, 0);
+    // The round falls due before member 1 draws their share
+    await sleep(1_500);
+
+    await expectError(
+      program.methods
+        .punish()
+        .accountsStrict({
+          admin: cycle.creator.publicKey,
+          config: cycle.config,
+          beneficiary: cycle.beneficiaries[0],
+          vault: cycle.vault,
+        })
+        .signers([cycle.creator])
+        .rpc(),
+      "PaymentStillOnTime"
+    );
+    // Nor can they be asked to pay the round again
+    await expectError(depositMonthly(cycle, 0), "AlreadyPaidMonthly");
+
+    await withdraw(cycle, 1);
+    assert.strictEqual((await fetchBeneficiary(cycle, 0)).strikes, 0);
+  });
+});
@@ -0,0 +1,79 @@
+// user-040: closing beneficiary accounts
+import { describe, it } from "node:test";
+import assert from "assert";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { BN } from "bn.js";
+import {
+  Cycle,
+  createCycle,
+  earlyExit,
+  expectError,
+  payRound,
+  program,
+  provider,
+  sleep,
+  withdraw,
+} from "./utils/cycle.ts";
+
+const closeBeneficiary = (cycle: Cycle, i: number) =>
+  program.methods
+    .closeBeneficiary()
+    .accountsStrict({
+      signer: cycle.members[i].publicKey,
+      config: cycle.config,
+      beneficiary: cycle.beneficiaries[i],
+      rentPayer: cycle.creator.publicKey,
+    })
+    .signers([cycle.members[i]])
+    .rpc();
+
+describe("close beneficiary", () => {
+  it("keeps an early leaver's account open until the rotation ends", async () => {
+    const cycle = await createCycle({ members: 3 });
+    await payRound(cycle, [0, 1]);
+    await earlyExit(cycle, 2);
+
+    // Slot 2 still has a payout turn to be skipped
+    await expectError(closeBeneficiary(cycle, 2), "CycleNotComplete");
+
+    await withdraw(cycle, 0);
+    await payRound(cycle, [0, 1]);
+    await withdraw(cycle, 1);
+    await expectError(closeBeneficiary(cycle, 2), "CycleNotComplete");
+    assert.ok(await provider.connection.getAccountInfo(cycle.beneficiaries[2]));
+  });
+
+  it("closes after a cancelled cycle's refund and returns rent to the payer", async () => {
+    const deadline = Math.floor(Date.now() / 1000) + 2;
+    const cycle = await createCycle({
+      members: 3,
+      enroll: 1,
+      terms: { enrollmentDeadlineTs: new BN(deadline), minParticipants: 3 },
+    });
+    await sleep(4_000);
+
+    const closeEnrollmentAccounts = (beneficiary: PublicKey | null) => ({
+      signer: cycle.members[0].publicKey,
+      config: cycle.config,
+      vault: cycle.vault,
+      beneficiary,
+      systemProgram: SystemProgram.programId,
+    });
+    await program.methods
+      .cancelCycle()
+      .accountsStrict(closeEnrollmentAccounts(null))
+      .signers([cycle.members[0]])
+      .rpc();
+    await program.methods
+      .refundCollateral()
+      .accountsStrict(closeEnrollmentAccounts(cycle.beneficiaries[0]))
+      .signers([cycle.members[0]])
+      .rpc();
+
+    const payerBefore = await provider.connection.getBalance(cycle.creator.publicKey);
+    await closeBeneficiary(cycle, 0);
+
+    assert.strictEqual(await provider.connection.getAccountInfo(cycle.beneficiaries[0]), null);
+    assert.ok((await provider.connection.getBalance(cycle.creator.publicKey)) > payerBefore);
+  });
+});
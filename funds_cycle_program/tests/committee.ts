@@ -0,0 +1,163 @@
+// user-033: committee admin
+import { describe, it, before } from "node:test";
+import assert from "assert";
+import { AccountMeta, Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { BN } from "bn.js";
+import {
+  Cycle,
+  createCycle,
+  expectError,
+  fetchBeneficiary,
+  fetchConfig,
+  fundedKeypair,
+  noConfigChanges,
+  program,
+} from "./utils/cycle.ts";
+
+describe("committee admin", () => {
+  let cycle: Cycle;
+  let members: Keypair[];
+  let committee: PublicKey;
+  let authority: PublicKey;
+
+  const proposalPda = (id: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), committee.toBuffer(), new BN(id).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const nextProposalId = async () =>
+    (await program.account.committeeAccount.fetch(committee)).proposalCount.toNumber();
+
+  const propose = async (action: any, proposer = members[0]) => {
+    const id = await nextProposalId();
+    await program.methods
+      .createProposal(action)
+      .accountsStrict({
+        member: proposer.publicKey,
+        committee,
+        proposal: proposalPda(id),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([proposer])
+      .rpc();
+    return id;
+  };
+
+  const approve = (id: number, member: Keypair) =>
+    program.methods
+      .approveProposal()
+      .accountsStrict({ member: member.publicKey, committee, proposal: proposalPda(id) })
+      .signers([member])
+      .rpc();
+
+  const execute = (id: number, remaining: AccountMeta[] = [], executor = members[0]) =>
+    program.methods
+      .executeProposal()
+      .accountsStrict({
+        member: executor.publicKey,
+        config: cycle.config,
+        committee,
+        authority,
+        proposal: proposalPda(id),
+        fundsCycleProgram: program.programId,
+      })
+      .remainingAccounts(remaining)
+      .signers([executor])
+      .rpc();
+
+  // Admin seat and config, the first two accounts of most admin instructions
+  const adminAccounts = (): AccountMeta[] => [
+    { pubkey: authority, isSigner: false, isWritable: false },
+    { pubkey: cycle.config, isSigner: false, isWritable: true },
+  ];
+
+  const passed = async (action: any, remaining: AccountMeta[] = []) => {
+    const id = await propose(action);
+    await approve(id, members[1]);
+    await execute(id, remaining);
+    return id;
+  };
+
+  before(async () => {
+    cycle = await createCycle({ members: 2, enroll: 0 });
+    members = [await fundedKeypair(), await fundedKeypair(), await fundedKeypair()];
+
+    [committee] = PublicKey.findProgramAddressSync(
+      [Buffer.from("committee"), cycle.config.toBuffer()],
+      program.programId
+    );
+    [authority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("committee_authority"), committee.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .createCommittee(members.map(m => m.publicKey), 2)
+      .accountsStrict({
+        admin: cycle.creator.publicKey,
+        config: cycle.config,
+        committee,
+        authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cycle.creator])
+      .rpc();
+  });
+
+  it("adds a beneficiary with rent paid by the executing member", async () => {
+    const wallet = cycle.members[0].publicKey;
+    await passed({ addBeneficiary: { wallet } }, [
+      ...adminAccounts(),
+      { pubkey: members[0].publicKey, isSigner: true, isWritable: true },
+      { pubkey: wallet, isSigner: false, isWritable: false },
+      { pubkey: cycle.beneficiaries[0], isSigner: false, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ]);
+
+    const beneficiary = await fetchBeneficiary(cycle, 0);
+    assert.ok(beneficiary.payer.equals(members[0].publicKey));
+  });
+
+  it("updates terms through an approved proposal", async () => {
+    const args = { ...noConfigChanges, keeperBounty: new BN(100) };
+    await passed({ updateConfig: { args } }, adminAccounts());
+
+    const config = await fetchConfig(cycle);
+    assert.strictEqual(config.keeperBounty.toNumber(), 100);
+  });
+
+  it("rejects executing below the threshold", async () => {
+    const id = await propose({ pause: {} });
+    await expectError(execute(id, adminAccounts()), "ThresholdNotMet");
+  });
+
+  it("rotates out a member and stales older proposals", async () => {
+    const stale = await propose({ pause: {} });
+    await passed({ removeMember: { member: members[2].publicKey } });
+
+    const state = await program.account.committeeAccount.fetch(committee);
+    assert.strictEqual(state.members.length, 2);
+    await expectError(approve(stale, members[1]), "StaleProposal");
+
+    // The removed member can no longer file proposals
+    await assert.rejects(propose({ pause: {} }, members[2]));
+  });
+
+  it("hands admin back to a single wallet", async () => {
+    const newAdmin = await fundedKeypair();
+    await passed({ proposeAdmin: { newAdmin: newAdmin.publicKey } }, adminAccounts());
+
+    await program.methods
+      .acceptAdmin()
+      .accountsStrict({ signer: newAdmin.publicKey, config: cycle.config })
+      .signers([newAdmin])
+      .rpc();
+    assert.ok((await fetchConfig(cycle)).admin.equals(newAdmin.publicKey));
+
+    // The committee no longer holds the admin seat
+    const id = await propose({ pause: {} });
+    await approve(id, members[1]);
+    await expectError(execute(id, adminAccounts()), "Unauthorized");
+  });
+});
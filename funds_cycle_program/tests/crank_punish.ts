@@ -0,0 +1,81 @@
+// user-036: keeper crank
+import { describe, it } from "node:test";
+import assert from "assert";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import {
+  Cycle,
+  createCycle,
+  depositCollateral,
+  depositMonthly,
+  expectError,
+  fetchBeneficiary,
+  fundedKeypair,
+  payRound,
+  program,
+  provider,
+  sleep,
+  withdraw,
+} from "./utils/cycle.ts";
+
+const crank = (cycle: Cycle, i: number, keeper: Keypair) =>
+  program.methods
+    .crankPunish()
+    .accountsStrict({
+      keeper: keeper.publicKey,
+      config: cycle.config,
+      beneficiary: cycle.beneficiaries[i],
+      vault: cycle.vault,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([keeper])
+    .rpc();
+
+// With a zero-day interval each round falls due the second it starts
+const terms = { keeperBounty: 500, strikeFine: 1_000, maxStrikes: 2 };
+
+describe("keeper crank", () => {
+  it("strikes an overdue member and pays the keeper", async () => {
+    const cycle = await createCycle({ terms });
+    const keeper = await fundedKeypair();
+    await depositMonthly(cycle, 0);
+    await sleep(1_500);
+
+    const keeperBefore = await provider.connection.getBalance(keeper.publicKey);
+    await crank(cycle, 1, keeper);
+
+    const member = await fetchBeneficiary(cycle, 1);
+    assert.strictEqual(member.strikes, 1);
+    assert.strictEqual(member.active, true);
+    assert.strictEqual(member.finesPaid.toNumber(), 1_500);
+    // The provider wallet pays the fee, the keeper only co-signs
+    assert.strictEqual(await provider.connection.getBalance(keeper.publicKey), keeperBefore + 500);
+
+    // Paid up for this round, so not crankable
+    await expectError(crank(cycle, 0, keeper), "PaymentStillOnTime");
+  });
+
+  it("new member not crankable", async () => {
+    // Slots fill and the cycle starts before the second member posts collateral
+    const cycle = await createCycle({ terms, postCollateral: false });
+    const keeper = await fundedKeypair();
+    await depositCollateral(cycle, 0);
+    await sleep(1_500);
+
+    await expectError(crank(cycle, 1, keeper), "CollateralNotPaid");
+    const member = await fetchBeneficiary(cycle, 1);
+    assert.strictEqual(member.strikes, 0);
+    assert.strictEqual(member.finesPaid.toNumber(), 0);
+  });
+
+  it("not crankable after rotation", async () => {
+    const cycle = await createCycle({ terms });
+    const keeper = await fundedKeypair();
+    for (let i = 0; i < cycle.members.length; i++) {
+      await payRound(cycle);
+      await withdraw(cycle, i);
+    }
+    await sleep(1_500);
+
+    await expectError(crank(cycle, 0, keeper), "CycleAlreadyComplete");
+  });
+});
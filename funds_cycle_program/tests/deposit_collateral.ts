@@ -0,0 +1,49 @@
+// user-027: collateral only while enrolling or running
+import { describe, it } from "node:test";
+import { SystemProgram } from "@solana/web3.js";
+import { BN } from "bn.js";
+import {
+  createCycle,
+  depositCollateral,
+  expectError,
+  program,
+  proposeDissolution,
+  ratifyDissolution,
+  sleep,
+} from "./utils/cycle.ts";
+
+describe("deposit collateral", () => {
+  it("is rejected once the cycle is cancelled", async () => {
+    const deadline = Math.floor(Date.now() / 1000) + 2;
+    const cycle = await createCycle({
+      members: 3,
+      enroll: 1,
+      postCollateral: false,
+      terms: { enrollmentDeadlineTs: new BN(deadline), minParticipants: 3 },
+    });
+    await sleep(4_000);
+
+    await program.methods
+      .cancelCycle()
+      .accountsStrict({
+        signer: cycle.members[0].publicKey,
+        config: cycle.config,
+        vault: cycle.vault,
+        beneficiary: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cycle.members[0]])
+      .rpc();
+
+    await expectError(depositCollateral(cycle, 0), "CycleNotActive");
+  });
+
+  it("is rejected once the cycle is dissolving", async () => {
+    const cycle = await createCycle({ postCollateral: false, terms: { voteWindowDays: 1 } });
+    await proposeDissolution(cycle);
+    await ratifyDissolution(cycle, 0);
+    await ratifyDissolution(cycle, 1);
+
+    await expectError(depositCollateral(cycle, 0), "CycleNotActive");
+  });
+});
@@ -0,0 +1,69 @@
+// user-038: dissolution
+import { describe, it } from "node:test";
+import assert from "assert";
+import { AccountMeta, Keypair } from "@solana/web3.js";
+import {
+  Cycle,
+  createCycle,
+  dissolutionPdaFor,
+  dissolutionVoteRecords,
+  expectError,
+  fetchConfig,
+  finalizeDissolution,
+  payRound,
+  program,
+  proposeDissolution,
+  provider,
+  ratifyDissolution,
+} from "./utils/cycle.ts";
+
+const closeDissolution = (cycle: Cycle, signer: Keypair, remaining: AccountMeta[] = []) =>
+  program.methods
+    .closeDissolution()
+    .accountsStrict({
+      signer: signer.publicKey,
+      config: cycle.config,
+      proposal: dissolutionPdaFor(cycle.config),
+      proposer: cycle.creator.publicKey,
+    })
+    .remainingAccounts(remaining)
+    .signers([signer])
+    .rpc();
+
+// Voting windows run in whole days, so the expiry path cannot be reached
+// on a local validator - only its guard is exercised here
+describe("dissolution", () => {
+  it("needs a voting window", async () => {
+    const cycle = await createCycle();
+    await expectError(proposeDissolution(cycle), "VoteWindowNotSet");
+  });
+
+  it("cannot be closed while voting is open", async () => {
+    const cycle = await createCycle({ terms: { voteWindowDays: 1 } });
+    await proposeDissolution(cycle);
+    await ratifyDissolution(cycle, 0);
+
+    await expectError(
+      closeDissolution(cycle, cycle.members[1], dissolutionVoteRecords(cycle, [0])),
+      "VoteWindowOpen"
+    );
+  });
+
+  it("finalizes on a majority and closes every vote record", async () => {
+    const cycle = await createCycle({ members: 3, terms: { voteWindowDays: 1 } });
+    await payRound(cycle);
+    await proposeDissolution(cycle);
+    await ratifyDissolution(cycle, 0);
+    await ratifyDissolution(cycle, 1);
+    assert.deepStrictEqual((await fetchConfig(cycle)).status, { dissolving: {} });
+
+    // Leaving a ratification's record behind is rejected
+    await expectError(finalizeDissolution(cycle, dissolutionVoteRecords(cycle, [0])), "IncompleteVoteRecords");
+    await finalizeDissolution(cycle, dissolutionVoteRecords(cycle, [0, 1]));
+
+    assert.deepStrictEqual((await fetchConfig(cycle)).status, { dissolved: {} });
+    for (const record of dissolutionVoteRecords(cycle, [0, 1]).filter((_, i) => i % 2 == 0)) {
+      assert.strictEqual(await provider.connection.getAccountInfo(record.pubkey), null);
+    }
+  });
+});
@@ -0,0 +1,51 @@
+// user-029: early exit
+import { describe, it } from "node:test";
+import assert from "assert";
+import {
+  createCycle,
+  depositMonthly,
+  earlyExit,
+  expectError,
+  fetchConfig,
+  fetchVault,
+  payRound,
+  proposeDissolution,
+  provider,
+  withdraw,
+} from "./utils/cycle.ts";
+
+describe("early exit", () => {
+  it("refunds only what the vault still holds for the member", async () => {
+    // Round 0 pays 80% out to slot 0 and keeps 200 of each payment back
+    const cycle = await createCycle({ members: 3 });
+    await payRound(cycle);
+    await withdraw(cycle, 0);
+    // Round 1: slot 2 pays in before the pot is drawn
+    await depositMonthly(cycle, 2);
+
+    const reserveBefore = (await fetchVault(cycle)).reserve.toNumber();
+
+    // Slot 1 owes nothing this round: collateral plus its kept back 200
+    let before = await provider.connection.getBalance(cycle.members[1].publicKey);
+    await earlyExit(cycle, 1);
+    assert.strictEqual(await provider.connection.getBalance(cycle.members[1].publicKey), before + 10_000 + 200);
+
+    // Slot 2 also takes back this round's undrawn payment
+    before = await provider.connection.getBalance(cycle.members[2].publicKey);
+    await earlyExit(cycle, 2);
+    assert.strictEqual(await provider.connection.getBalance(cycle.members[2].publicKey), before + 10_000 + 200 + 1_000);
+
+    // The share kept back for slot 0 stays in the reserve
+    const vault = await fetchVault(cycle);
+    assert.strictEqual(vault.reserve.toNumber(), reserveBefore - 400);
+    assert.strictEqual(vault.contributionPool.toNumber(), 0);
+    assert.strictEqual((await fetchConfig(cycle)).roundCollected.toNumber(), 0);
+  });
+
+  it("is closed while a dissolution vote is open", async () => {
+    const cycle = await createCycle({ terms: { voteWindowDays: 1 } });
+    await proposeDissolution(cycle);
+
+    await expectError(earlyExit(cycle, 0), "DissolutionPending");
+  });
+});
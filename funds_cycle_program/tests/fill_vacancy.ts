@@ -0,0 +1,86 @@
+// user-030: replacement members, with the turn checks from user-031
+import { describe, it } from "node:test";
+import assert from "assert";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import {
+  Cycle,
+  advanceRound,
+  beneficiaryPdaFor,
+  createCycle,
+  earlyExit,
+  enableClaiming,
+  expectError,
+  fetchConfig,
+  fetchVault,
+  fundedKeypair,
+  payRound,
+  program,
+  withdraw,
+} from "./utils/cycle.ts";
+
+const fillVacancy = (cycle: Cycle, vacatedSlot: number, wallet: Keypair) =>
+  program.methods
+    .fillVacancy()
+    .accountsStrict({
+      admin: cycle.creator.publicKey,
+      wallet: wallet.publicKey,
+      config: cycle.config,
+      vault: cycle.vault,
+      vacated: cycle.beneficiaries[vacatedSlot],
+      beneficiary: beneficiaryPdaFor(cycle.config, wallet.publicKey),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.creator, wallet])
+    .rpc();
+
+describe("fill vacancy", () => {
+  it("lets a replacement take over a slot whose turn is ahead, with catch-up", async () => {
+    const cycle = await createCycle({ members: 3 });
+    await earlyExit(cycle, 2);
+    await payRound(cycle, [0, 1]);
+    await withdraw(cycle, 0);
+
+    const replacement = await fundedKeypair();
+    const vaultBefore = await fetchVault(cycle);
+    await fillVacancy(cycle, 2, replacement);
+
+    const filled = await program.account.beneficiaryAccount.fetch(
+      beneficiaryPdaFor(cycle.config, replacement.publicKey)
+    );
+    assert.strictEqual(filled.index, 2);
+    // One round completed, so one contribution of catch-up
+    assert.strictEqual(filled.contributions.toNumber(), 1_000);
+
+    const vault = await fetchVault(cycle);
+    assert.strictEqual(vault.collateralHeld.toNumber(), vaultBefore.collateralHeld.toNumber() + 10_000);
+    assert.strictEqual(vault.reserve.toNumber(), vaultBefore.reserve.toNumber() + 1_000);
+    assert.strictEqual((await fetchConfig(cycle)).activeMembers, 3);
+
+    // The slot is taken now
+    await expectError(fillVacancy(cycle, 2, await fundedKeypair()), "VacancyAlreadyFilled");
+  });
+
+  it("rejects a slot whose turn was skipped", async () => {
+    const cycle = await createCycle({ members: 3 });
+    await earlyExit(cycle, 0);
+    // A vacant single-recipient turn is skipped straight away
+    await advanceRound(cycle, [cycle.beneficiaries[0]]);
+
+    await expectError(fillVacancy(cycle, 0, await fundedKeypair()), "VacancyTurnPassed");
+  });
+
+  it("rejects a fill once the rotation has finished", async () => {
+    const cycle = await createCycle({ members: 3 });
+    await earlyExit(cycle, 2);
+    for (const i of [0, 1]) {
+      await payRound(cycle, [0, 1]);
+      await withdraw(cycle, i);
+    }
+    // Slot 2's turn is the last one, and it is vacant
+    await advanceRound(cycle, [cycle.beneficiaries[2]]);
+
+    await expectError(fillVacancy(cycle, 2, await fundedKeypair()), "CycleAlreadyComplete");
+    await enableClaiming(cycle);
+    await expectError(fillVacancy(cycle, 2, await fundedKeypair()), "AlreadyClaimable");
+  });
+});
@@ -222,6 +222,7 @@ describe("funds_cycle_program", () => {
           .accountsStrict({
             admin: admin.publicKey,
             config: configPda,
+            payer: admin.publicKey,
             wallet,
             beneficiary: beneficiaryPda,
             systemProgram: SystemProgram.programId,
@@ -458,10 +459,12 @@ describe("funds_cycle_program", () => {
             program.methods
               .withdraw()
               .accountsStrict({
-                wallet: beneficiary1.publicKey,
+                signer: beneficiary1.publicKey,
                 config: configPda,
                 beneficiary: beneficiary1Pda,
                 vault: vaultPda,
+                destination: beneficiary1.publicKey,
+                stream: null,
                 systemProgram: SystemProgram.programId,
               }),
             [beneficiary1],
@@ -489,8 +492,26 @@ describe("funds_cycle_program", () => {
 
         const ben1Before = await program.account.beneficiaryAccount.fetch(beneficiary1Pda);
         
+        const config = await program.account.configAccount.fetch(configPda);
+        const paidThisRound = ben1Before.monthlyPaid && ben1Before.paidRound === config.roundsCompleted;
+
         if (!ben1Before.active) {
           console.log("ℹ️ Beneficiary1 already inactive from previous test");
+        } else if (paidThisRound) {
+          // Paid up for the current round, so the admin cannot punish
+          await assert.rejects(
+            program.methods
+              .punish()
+              .accountsStrict({
+                admin: admin.publicKey,
+                config: configPda,
+                beneficiary: beneficiary1Pda,
+                vault: vaultPda,
+              })
+              .rpc(),
+            (err: any) => err?.error?.errorCode?.code === "PaymentStillOnTime"
+          );
+          console.log("✅ Punishment rejected for a member who paid this round");
         } else {
           await executeTransaction(
             program.methods
@@ -499,6 +520,7 @@ describe("funds_cycle_program", () => {
                 admin: admin.publicKey,
                 config: configPda,
                 beneficiary: beneficiary1Pda,
+                vault: vaultPda,
               }),
             [],
             "Admin Punish",
@@ -0,0 +1,68 @@
+// user-026: admin invites
+import { describe, it } from "node:test";
+import assert from "assert";
+import { Ed25519Program, Keypair, SYSVAR_INSTRUCTIONS_PUBKEY, SystemProgram } from "@solana/web3.js";
+import { BN } from "bn.js";
+import {
+  Cycle,
+  beneficiaryPdaFor,
+  createCycle,
+  expectError,
+  fetchConfig,
+  fundedKeypair,
+  program,
+} from "./utils/cycle.ts";
+
+// config (32) | wallet (32) | slot (1) | expiry_ts (8, little endian)
+const inviteMessage = (cycle: Cycle, wallet: Keypair, slot: number, expiry: number) =>
+  Buffer.concat([
+    cycle.config.toBuffer(),
+    wallet.publicKey.toBuffer(),
+    Buffer.from([slot]),
+    new BN(expiry).toArrayLike(Buffer, "le", 8),
+  ]);
+
+const join = (cycle: Cycle, wallet: Keypair, slot: number, expiry: number, signer: Keypair = cycle.creator) =>
+  program.methods
+    .joinWithInvite(slot, new BN(expiry))
+    .accountsStrict({
+      wallet: wallet.publicKey,
+      config: cycle.config,
+      beneficiary: beneficiaryPdaFor(cycle.config, wallet.publicKey),
+      instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    })
+    .preInstructions([
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signer.secretKey,
+        message: inviteMessage(cycle, wallet, slot, expiry),
+      }),
+    ])
+    .signers([wallet])
+    .rpc();
+
+const inAnHour = () => Math.floor(Date.now() / 1000) + 3_600;
+
+describe("admin invites", () => {
+  it("lets an invited wallet take its slot and pay its own rent", async () => {
+    const cycle = await createCycle({ enroll: 0 });
+    const wallet = await fundedKeypair();
+    await join(cycle, wallet, 0, inAnHour());
+
+    const beneficiary = await program.account.beneficiaryAccount.fetch(
+      beneficiaryPdaFor(cycle.config, wallet.publicKey)
+    );
+    assert.strictEqual(beneficiary.index, 0);
+    assert.ok(beneficiary.payer.equals(wallet.publicKey));
+    assert.strictEqual((await fetchConfig(cycle)).currentIndex, 1);
+  });
+
+  it("rejects invites for another slot, expired or not signed by the admin", async () => {
+    const cycle = await createCycle({ enroll: 0 });
+    const wallet = await fundedKeypair();
+
+    await expectError(join(cycle, wallet, 1, inAnHour()), "InviteSlotMismatch");
+    await expectError(join(cycle, wallet, 0, Math.floor(Date.now() / 1000) - 60), "InviteExpired");
+    await expectError(join(cycle, wallet, 0, inAnHour(), await fundedKeypair()), "InvalidInviteSignature");
+  });
+});
@@ -0,0 +1,78 @@
+// user-050: nominee claims
+import { describe, it } from "node:test";
+import assert from "assert";
+import { Keypair } from "@solana/web3.js";
+import {
+  Cycle,
+  claimCollateral,
+  claimVested,
+  createCycle,
+  enableClaiming,
+  expectError,
+  fetchBeneficiary,
+  fundedKeypair,
+  payRound,
+  program,
+  provider,
+  runRotation,
+  streamPdaFor,
+  withdraw,
+} from "./utils/cycle.ts";
+
+const setNominee = (cycle: Cycle, i: number, nominee: Keypair) =>
+  program.methods
+    .setNominee(nominee.publicKey)
+    .accountsStrict({
+      wallet: cycle.members[i].publicKey,
+      config: cycle.config,
+      beneficiary: cycle.beneficiaries[i],
+    })
+    .signers([cycle.members[i]])
+    .rpc();
+
+// Dormancy runs in whole days, so a nominee can never act on a local
+// validator - these flows cover the guards and the member's own path
+describe("nominee claims", () => {
+  it("a nominee cannot withdraw for an active member", async () => {
+    const cycle = await createCycle({ terms: { nomineeDormancyDays: 1 } });
+    const nominee = await fundedKeypair();
+    await setNominee(cycle, 0, nominee);
+    await payRound(cycle);
+
+    await expectError(withdraw(cycle, 0, null, nominee), "NomineeNotEligible");
+    await expectError(withdraw(cycle, 0, null, await fundedKeypair()), "NomineeNotEligible");
+
+    // The member still withdraws to their own destination
+    await withdraw(cycle, 0);
+    assert.ok((await fetchBeneficiary(cycle, 0)).payoutReceived);
+  });
+
+  it("a nominee cannot claim a stream early, and the member's rent comes back", async () => {
+    const cycle = await createCycle({ terms: { nomineeDormancyDays: 1, vestPayouts: true } });
+    const nominee = await fundedKeypair();
+    await setNominee(cycle, 0, nominee);
+    await payRound(cycle);
+
+    const stream = streamPdaFor(cycle.beneficiaries[0]);
+    await withdraw(cycle, 0, stream);
+    await expectError(claimVested(cycle, 0, nominee), "NomineeNotEligible");
+
+    const before = await provider.connection.getBalance(cycle.members[0].publicKey);
+    const streamRent = await provider.connection.getBalance(stream);
+    await claimVested(cycle, 0);
+    assert.strictEqual(
+      await provider.connection.getBalance(cycle.members[0].publicKey),
+      before + 1_600 + streamRent
+    );
+  });
+
+  it("a nominee cannot claim collateral for an active member", async () => {
+    const cycle = await createCycle({ terms: { nomineeDormancyDays: 1 } });
+    const nominee = await fundedKeypair();
+    await setNominee(cycle, 0, nominee);
+    await runRotation(cycle);
+    await enableClaiming(cycle);
+
+    await expectError(claimCollateral(cycle, 0, nominee), "NomineeNotEligible");
+  });
+});
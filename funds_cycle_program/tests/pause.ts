@@ -0,0 +1,29 @@
+// user-037: emergency pause
+import { describe, it } from "node:test";
+import { Keypair } from "@solana/web3.js";
+import {
+  Cycle,
+  addBeneficiary,
+  createCycle,
+  expectError,
+  program,
+} from "./utils/cycle.ts";
+
+const setPaused = (cycle: Cycle, paused: boolean, admin: Keypair = cycle.creator) =>
+  (paused ? program.methods.pause() : program.methods.unpause())
+    .accountsStrict({ admin: admin.publicKey, config: cycle.config })
+    .signers([admin])
+    .rpc();
+
+describe("emergency pause", () => {
+  it("holds enrollment until the cycle is unpaused", async () => {
+    const cycle = await createCycle({ enroll: 1 });
+    await setPaused(cycle, true);
+
+    // Filling the last slot would start the round mid-pause
+    await expectError(addBeneficiary(cycle, 1), "CyclePaused");
+
+    await setPaused(cycle, false);
+    await addBeneficiary(cycle, 1);
+  });
+});
@@ -0,0 +1,90 @@
+import { describe, it, before } from "node:test";
+import assert from "assert";
+import {
+  PublicKey,
+  STAKE_CONFIG_ID,
+  StakeProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  SystemProgram,
+} from "@solana/web3.js";
+import { BN } from "bn.js";
+import {
+  Cycle,
+  createCycle,
+  expectError,
+  fetchVault,
+  payRound,
+  program,
+  provider,
+  withdraw,
+} from "./utils/cycle.ts";
+
+describe("reserve staking", () => {
+  let cycle: Cycle;
+  let stakeAccount: PublicKey;
+  let voteAccount: PublicKey;
+  let minimumStake: number;
+
+  const stakeReserve = (amount: number) =>
+    program.methods
+      .stakeReserve(new BN(amount))
+      .accountsStrict({
+        admin: cycle.creator.publicKey,
+        config: cycle.config,
+        vault: cycle.vault,
+        stakeAccount,
+        voteAccount,
+        clock: SYSVAR_CLOCK_PUBKEY,
+        rent: SYSVAR_RENT_PUBKEY,
+        stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+        stakeConfig: STAKE_CONFIG_ID,
+        stakeProgram: StakeProgram.programId,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cycle.creator])
+      .rpc();
+
+  before(async () => {
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(StakeProgram.space);
+    const minimumDelegation = (await provider.connection.getStakeMinimumDelegation()).value;
+    minimumStake = rent + minimumDelegation;
+
+    // Three members at 50% keep half of each round as reserve, and the
+    // first round leaves two more before the final one
+    const monthly = minimumStake;
+    cycle = await createCycle({
+      members: 3,
+      monthly,
+      withdrawPercent: 50,
+      memberLamports: monthly * 2 + 50_000_000,
+    });
+    await payRound(cycle);
+    await withdraw(cycle, 0);
+
+    [stakeAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reserve_stake"), cycle.config.toBuffer()],
+      program.programId
+    );
+    voteAccount = new PublicKey((await provider.connection.getVoteAccounts()).current[0].votePubkey);
+  });
+
+  it("rejects a stake below rent plus the minimum delegation", async () => {
+    await expectError(stakeReserve(minimumStake - 1), "InvalidYieldAmount");
+  });
+
+  it("funds, initializes and delegates the stake account from the vault", async () => {
+    const vaultBefore = await provider.connection.getBalance(cycle.vault);
+
+    await stakeReserve(minimumStake);
+
+    const vault = await fetchVault(cycle);
+    assert.strictEqual(vault.reserveStaked.toNumber(), minimumStake);
+    assert.strictEqual(await provider.connection.getBalance(cycle.vault), vaultBefore - minimumStake);
+
+    const stake = await provider.connection.getAccountInfo(stakeAccount);
+    assert.ok(stake?.owner.equals(StakeProgram.programId), "stake account should belong to the stake program");
+    assert.strictEqual(stake?.lamports, minimumStake);
+  });
+});
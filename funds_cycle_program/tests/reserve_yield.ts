@@ -0,0 +1,182 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { type MockYield } from "../target/types/mock_yield.ts";
+import { describe, it, before } from "node:test";
+import assert from "assert";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { BN } from "bn.js";
+import {
+  Cycle,
+  createCycle,
+  dissolutionVoteRecords,
+  earlyExit,
+  expectError,
+  fetchConfig,
+  fetchVault,
+  finalizeDissolution,
+  fund,
+  payRound,
+  program,
+  proposeDissolution,
+  provider,
+  ratifyDissolution,
+  withdraw,
+} from "./utils/cycle.ts";
+
+describe("reserve yield", () => {
+  const mockYield = anchor.workspace.MockYield as Program<MockYield>;
+
+  let cycle: Cycle;
+  let position: PublicKey;
+
+  const manageReserveAccounts = () => ({
+    admin: cycle.creator.publicKey,
+    config: cycle.config,
+    vault: cycle.vault,
+    yieldProgram: mockYield.programId,
+    position,
+    systemProgram: SystemProgram.programId,
+  });
+
+  before(async () => {
+    // Two members at 50%: the first round leaves 1_000 lamports of reserve
+    cycle = await createCycle({ withdrawPercent: 50, terms: { yieldProgram: mockYield.programId } });
+    await payRound(cycle);
+    await withdraw(cycle, 0);
+
+    [position] = PublicKey.findProgramAddressSync(
+      [Buffer.from("position"), cycle.vault.toBuffer()],
+      mockYield.programId
+    );
+    await mockYield.methods
+      .openPosition()
+      .accountsStrict({
+        payer: provider.wallet.publicKey,
+        depositor: cycle.vault,
+        position,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("deploys reserve into the yield program", async () => {
+    const vaultBefore = await provider.connection.getBalance(cycle.vault);
+    const positionBefore = await provider.connection.getBalance(position);
+
+    await program.methods
+      .deployReserve(new BN(600))
+      .accountsStrict(manageReserveAccounts())
+      .signers([cycle.creator])
+      .rpc();
+
+    const vault = await fetchVault(cycle);
+    assert.strictEqual(vault.reserveDeployed.toNumber(), 600);
+    assert.strictEqual(vault.reserve.toNumber(), 400);
+    assert.strictEqual(await provider.connection.getBalance(cycle.vault), vaultBefore - 600);
+    assert.strictEqual(await provider.connection.getBalance(position), positionBefore + 600);
+
+    const recorded = await mockYield.account.position.fetch(position);
+    assert.strictEqual(recorded.principal.toNumber(), 600);
+  });
+
+  it("rejects deploying more than the reserve", async () => {
+    await expectError(
+      program.methods
+        .deployReserve(new BN(10_000))
+        .accountsStrict(manageReserveAccounts())
+        .signers([cycle.creator])
+        .rpc(),
+      "InsufficientReserve"
+    );
+  });
+
+  it("recalls principal and books the yield separately", async () => {
+    // Anything sent to the position is yield for the mock
+    await fund(position, 90);
+
+    await program.methods
+      .recallReserve(new BN(600))
+      .accountsStrict(manageReserveAccounts())
+      .signers([cycle.creator])
+      .rpc();
+
+    const vault = await fetchVault(cycle);
+    assert.strictEqual(vault.reserveDeployed.toNumber(), 0);
+    assert.strictEqual(vault.reserve.toNumber(), 1_000);
+    assert.strictEqual(vault.yieldAccrued.toNumber(), 90);
+  });
+});
+
+describe("reserve yield outside the rotation", () => {
+  const mockYield = anchor.workspace.MockYield as Program<MockYield>;
+
+  // Round 0 paid out half, leaving 1_000 in reserve: 500 held for each member
+  const cycleWithReserve = async (terms = {}) => {
+    const cycle = await createCycle({
+      withdrawPercent: 50,
+      terms: { yieldProgram: mockYield.programId, ...terms },
+    });
+    await payRound(cycle);
+    await withdraw(cycle, 0);
+
+    const [position] = PublicKey.findProgramAddressSync(
+      [Buffer.from("position"), cycle.vault.toBuffer()],
+      mockYield.programId
+    );
+    await mockYield.methods
+      .openPosition()
+      .accountsStrict({
+        payer: provider.wallet.publicKey,
+        depositor: cycle.vault,
+        position,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const manageReserve = (method: "deployReserve" | "recallReserve", amount: number) =>
+      program.methods[method](new BN(amount))
+        .accountsStrict({
+          admin: cycle.creator.publicKey,
+          config: cycle.config,
+          vault: cycle.vault,
+          yieldProgram: mockYield.programId,
+          position,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([cycle.creator])
+        .rpc();
+
+    return { cycle, manageReserve };
+  };
+
+  it("holds an early exit until deployed reserve is recalled", async () => {
+    const { cycle, manageReserve } = await cycleWithReserve();
+    await manageReserve("deployReserve", 600);
+
+    // Only 400 is liquid, slot 1 is owed 500 of it
+    await expectError(earlyExit(cycle, 1), "ReserveDeployed");
+
+    await manageReserve("recallReserve", 600);
+    const before = await provider.connection.getBalance(cycle.members[1].publicKey);
+    await earlyExit(cycle, 1);
+    assert.strictEqual(await provider.connection.getBalance(cycle.members[1].publicKey), before + 10_000 + 500);
+  });
+
+  it("recalls deployed reserve while dissolving so the cycle can finalize", async () => {
+    const { cycle, manageReserve } = await cycleWithReserve({ voteWindowDays: 1 });
+    await manageReserve("deployReserve", 600);
+    await proposeDissolution(cycle);
+    await ratifyDissolution(cycle, 0);
+    await ratifyDissolution(cycle, 1);
+
+    const records = dissolutionVoteRecords(cycle, [0, 1]);
+    await expectError(finalizeDissolution(cycle, records), "ReserveDeployed");
+    await expectError(manageReserve("deployReserve", 100), "CycleNotActive");
+
+    await manageReserve("recallReserve", 600);
+    await finalizeDissolution(cycle, records);
+
+    assert.deepStrictEqual((await fetchConfig(cycle)).status, { dissolved: {} });
+    assert.strictEqual((await fetchVault(cycle)).reserveDeployed.toNumber(), 0);
+  });
+});
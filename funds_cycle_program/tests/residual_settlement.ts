@@ -0,0 +1,43 @@
+// user-039: residual settlement at claim time
+import { describe, it } from "node:test";
+import assert from "assert";
+import {
+  claimCollateral,
+  createCycle,
+  enableClaiming,
+  expectError,
+  fetchConfig,
+  fetchVault,
+  provider,
+  runRotation,
+} from "./utils/cycle.ts";
+
+describe("residual settlement", () => {
+  it("shares the reserve out with collateral and leaves only rent", async () => {
+    // Three members at 80%: each round keeps 600 lamports back as reserve
+    const cycle = await createCycle({ members: 3 });
+    await runRotation(cycle);
+
+    await expectError(enableClaiming(cycle, [0, 1]), "IncompleteBeneficiarySet");
+    await enableClaiming(cycle);
+
+    const config = await fetchConfig(cycle);
+    assert.strictEqual(config.residualPool.toNumber(), 1_800);
+    assert.strictEqual(config.residualWeight.toNumber(), 9_000);
+
+    for (let i = 0; i < 3; i++) {
+      const before = await provider.connection.getBalance(cycle.members[i].publicKey);
+      await claimCollateral(cycle, i);
+      const after = await provider.connection.getBalance(cycle.members[i].publicKey);
+      assert.strictEqual(after - before, 10_000 + 600);
+    }
+
+    const vault = await fetchVault(cycle);
+    assert.strictEqual(vault.reserve.toNumber(), 0);
+    assert.strictEqual(vault.collateralHeld.toNumber(), 0);
+
+    const info = await provider.connection.getAccountInfo(cycle.vault);
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(info!.data.length);
+    assert.strictEqual(info!.lamports, rent);
+  });
+});
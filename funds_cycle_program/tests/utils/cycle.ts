@@ -0,0 +1,358 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { type FundsCycleProgram } from "../../target/types/funds_cycle_program.ts";
+import { AccountMeta, Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
+import assert from "assert";
+
+// Shared setup for the flow tests. Every flow runs on a fresh cycle with
+// its own creator and members, funded from the provider wallet, so the
+// flows never depend on each other or on the lifecycle suite's state.
+
+export const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+export const program = anchor.workspace.FundsCycleProgram as Program<FundsCycleProgram>;
+
+export const configPdaFor = (creator: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("config"), creator.toBuffer()], program.programId)[0];
+
+export const vaultPdaFor = (config: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("vault"), config.toBuffer()], program.programId)[0];
+
+export const beneficiaryPdaFor = (config: PublicKey, wallet: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("beneficiary"), config.toBuffer(), wallet.toBuffer()],
+    program.programId
+  )[0];
+
+export const dissolutionPdaFor = (config: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("dissolution"), config.toBuffer()], program.programId)[0];
+
+export const streamPdaFor = (beneficiary: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("stream"), beneficiary.toBuffer()], program.programId)[0];
+
+export const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+// Sends lamports from the provider wallet
+export const fund = async (to: PublicKey, lamports: number) => {
+  const tx = new Transaction().add(
+    SystemProgram.transfer({ fromPubkey: provider.wallet.publicKey, toPubkey: to, lamports })
+  );
+  await provider.sendAndConfirm(tx, []);
+};
+
+export const fundedKeypair = async (lamports = 50_000_000) => {
+  const keypair = Keypair.generate();
+  await fund(keypair.publicKey, lamports);
+  return keypair;
+};
+
+// Asserts the transaction fails with the given `FundCycleError` variant
+export const expectError = async (promise: Promise<unknown>, code: string) => {
+  await assert.rejects(promise, (err: any) => {
+    const actual = err?.error?.errorCode?.code ?? err?.errorCode?.code;
+    assert.strictEqual(actual, code, `expected ${code}, got ${actual ?? err}`);
+    return true;
+  });
+};
+
+// Every `UpdateConfigArgs` field, left unchanged
+export const noConfigChanges = {
+  enrollmentDeadlineTs: null,
+  minParticipants: null,
+  exitForfeitPercent: null,
+  exitPenaltyPercent: null,
+  skipPolicy: null,
+  punishMode: null,
+  voteWindowDays: null,
+  voteMajorityPercent: null,
+  maxStrikes: null,
+  strikeFine: null,
+  strikeDecayRounds: null,
+  keeperBounty: null,
+  yieldProgram: null,
+  mode: null,
+  payoutsPerRound: null,
+  vestPayouts: null,
+  nomineeDormancyDays: null,
+};
+
+export type Cycle = {
+  creator: Keypair;
+  config: PublicKey;
+  vault: PublicKey;
+  members: Keypair[];
+  beneficiaries: PublicKey[];
+  collateral: BN;
+  monthly: BN;
+};
+
+export type CycleOptions = {
+  members?: number;
+  collateral?: number;
+  monthly?: number;
+  intervalDays?: number;
+  withdrawPercent?: number;
+  terms?: Partial<typeof noConfigChanges> | Record<string, unknown>;
+  // Slots added while enrollment is open - a full cycle starts by itself
+  enroll?: number;
+  postCollateral?: boolean;
+  memberLamports?: number;
+};
+
+export const createCycle = async (options: CycleOptions = {}): Promise<Cycle> => {
+  const memberCount = options.members ?? 2;
+  const collateral = new BN(options.collateral ?? 10_000);
+  const monthly = new BN(options.monthly ?? 1_000);
+
+  const creator = await fundedKeypair();
+  const config = configPdaFor(creator.publicKey);
+  const vault = vaultPdaFor(config);
+
+  await program.methods
+    .initialize(monthly, collateral, options.intervalDays ?? 0, memberCount, options.withdrawPercent ?? 80)
+    .accountsStrict({
+      admin: creator.publicKey,
+      config,
+      vault,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([creator])
+    .rpc();
+
+  if (options.terms) {
+    await program.methods
+      .updateConfig({ ...noConfigChanges, ...options.terms } as any)
+      .accountsStrict({ admin: creator.publicKey, config })
+      .signers([creator])
+      .rpc();
+  }
+
+  const members: Keypair[] = [];
+  for (let i = 0; i < memberCount; i++) {
+    members.push(await fundedKeypair(options.memberLamports));
+  }
+  const beneficiaries = members.map(member => beneficiaryPdaFor(config, member.publicKey));
+  const cycle = { creator, config, vault, members, beneficiaries, collateral, monthly };
+
+  const enroll = options.enroll ?? memberCount;
+  for (let i = 0; i < enroll; i++) {
+    await addBeneficiary(cycle, i);
+    if (options.postCollateral ?? true) {
+      await depositCollateral(cycle, i);
+    }
+  }
+
+  return cycle;
+};
+
+export const addBeneficiary = (cycle: Cycle, i: number) =>
+  program.methods
+    .addBeneficiary()
+    .accountsStrict({
+      admin: cycle.creator.publicKey,
+      config: cycle.config,
+      payer: cycle.creator.publicKey,
+      wallet: cycle.members[i].publicKey,
+      beneficiary: cycle.beneficiaries[i],
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.creator])
+    .rpc();
+
+const depositAccounts = (cycle: Cycle, i: number) => ({
+  wallet: cycle.members[i].publicKey,
+  config: cycle.config,
+  beneficiary: cycle.beneficiaries[i],
+  vault: cycle.vault,
+  systemProgram: SystemProgram.programId,
+});
+
+export const depositCollateral = (cycle: Cycle, i: number) =>
+  program.methods
+    .depositCollateral()
+    .accountsStrict(depositAccounts(cycle, i))
+    .signers([cycle.members[i]])
+    .rpc();
+
+export const depositMonthly = (cycle: Cycle, i: number) =>
+  program.methods
+    .depositMonthly()
+    .accountsStrict(depositAccounts(cycle, i))
+    .signers([cycle.members[i]])
+    .rpc();
+
+export const payRound = async (cycle: Cycle, slots: number[] = cycle.members.map((_, i) => i)) => {
+  for (const i of slots) {
+    await depositMonthly(cycle, i);
+  }
+};
+
+// Signed by the member by default, or by their nominee
+export const withdraw = (
+  cycle: Cycle,
+  i: number,
+  stream: PublicKey | null = null,
+  signer: Keypair = cycle.members[i],
+  destination = signer.publicKey
+) =>
+  program.methods
+    .withdraw()
+    .accountsStrict({
+      signer: signer.publicKey,
+      config: cycle.config,
+      beneficiary: cycle.beneficiaries[i],
+      vault: cycle.vault,
+      destination,
+      stream,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc();
+
+// The beneficiary account is only passed when a nominee claims
+export const claimVested = (cycle: Cycle, i: number, signer: Keypair = cycle.members[i], destination = signer.publicKey) =>
+  program.methods
+    .claimVested()
+    .accountsStrict({
+      signer: signer.publicKey,
+      config: cycle.config,
+      vault: cycle.vault,
+      stream: streamPdaFor(cycle.beneficiaries[i]),
+      beneficiary: signer === cycle.members[i] ? null : cycle.beneficiaries[i],
+      wallet: cycle.members[i].publicKey,
+      destination,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([signer])
+    .rpc();
+
+export const earlyExit = (cycle: Cycle, i: number) =>
+  program.methods
+    .earlyExit()
+    .accountsStrict({
+      wallet: cycle.members[i].publicKey,
+      config: cycle.config,
+      beneficiary: cycle.beneficiaries[i],
+      vault: cycle.vault,
+      dissolution: dissolutionPdaFor(cycle.config),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.members[i]])
+    .rpc();
+
+export const fetchConfig = (cycle: Cycle) => program.account.configAccount.fetch(cycle.config);
+export const fetchVault = (cycle: Cycle) => program.account.vaultAccount.fetch(cycle.vault);
+export const fetchBeneficiary = (cycle: Cycle, i: number) =>
+  program.account.beneficiaryAccount.fetch(cycle.beneficiaries[i]);
+
+const claimAccounts = (cycle: Cycle) => ({
+  signer: cycle.creator.publicKey,
+  config: cycle.config,
+  vault: cycle.vault,
+  beneficiary: null,
+  destination: null,
+  systemProgram: SystemProgram.programId,
+});
+
+// Passes every active beneficiary account, as `enable_claiming` requires
+export const enableClaiming = (cycle: Cycle, slots: number[] = cycle.members.map((_, i) => i)) =>
+  program.methods
+    .enableClaiming()
+    .accountsStrict(claimAccounts(cycle))
+    .remainingAccounts(
+      slots.map(i => ({ pubkey: cycle.beneficiaries[i], isWritable: false, isSigner: false }))
+    )
+    .signers([cycle.creator])
+    .rpc();
+
+export const claimCollateral = (cycle: Cycle, i: number, signer: Keypair = cycle.members[i], destination = signer.publicKey) =>
+  program.methods
+    .claimCollateral()
+    .accountsStrict({
+      ...claimAccounts(cycle),
+      signer: signer.publicKey,
+      beneficiary: cycle.beneficiaries[i],
+      destination,
+    })
+    .signers([signer])
+    .rpc();
+
+// Pays and withdraws every round of the rotation, one recipient per round
+export const runRotation = async (cycle: Cycle) => {
+  for (let i = 0; i < cycle.members.length; i++) {
+    await payRound(cycle);
+    await withdraw(cycle, i);
+  }
+};
+
+// Moves a stalled round on, passing the account holding each of the
+// round's slots in order
+export const advanceRound = (cycle: Cycle, slots: PublicKey[], signer: Keypair = cycle.creator) =>
+  program.methods
+    .advanceRound()
+    .accountsStrict({
+      signer: signer.publicKey,
+      config: cycle.config,
+      vault: cycle.vault,
+    })
+    .remainingAccounts(slots.map(pubkey => ({ pubkey, isWritable: true, isSigner: false })))
+    .signers([signer])
+    .rpc();
+
+export const votePdaFor = (proposal: PublicKey, voter: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("vote"), proposal.toBuffer(), voter.toBuffer()], program.programId)[0];
+
+export const proposeDissolution = (cycle: Cycle) =>
+  program.methods
+    .proposeDissolution()
+    .accountsStrict({
+      admin: cycle.creator.publicKey,
+      config: cycle.config,
+      payer: cycle.creator.publicKey,
+      proposal: dissolutionPdaFor(cycle.config),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.creator])
+    .rpc();
+
+export const ratifyDissolution = (cycle: Cycle, i: number) => {
+  const proposal = dissolutionPdaFor(cycle.config);
+  return program.methods
+    .ratifyDissolution()
+    .accountsStrict({
+      voter: cycle.members[i].publicKey,
+      config: cycle.config,
+      voterBeneficiary: cycle.beneficiaries[i],
+      proposal,
+      voteRecord: votePdaFor(proposal, cycle.members[i].publicKey),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.members[i]])
+    .rpc();
+};
+
+// A `(vote_record, voter)` pair for each member who ratified
+export const dissolutionVoteRecords = (cycle: Cycle, voters: number[]): AccountMeta[] =>
+  voters.flatMap(i => [
+    { pubkey: votePdaFor(dissolutionPdaFor(cycle.config), cycle.members[i].publicKey), isWritable: true, isSigner: false },
+    { pubkey: cycle.members[i].publicKey, isWritable: true, isSigner: false },
+  ]);
+
+// Passes every member's beneficiary account, then the vote records
+export const finalizeDissolution = (cycle: Cycle, records: AccountMeta[]) =>
+  program.methods
+    .finalizeDissolution()
+    .accountsStrict({
+      signer: cycle.creator.publicKey,
+      config: cycle.config,
+      vault: cycle.vault,
+      proposal: dissolutionPdaFor(cycle.config),
+      proposer: cycle.creator.publicKey,
+    })
+    .remainingAccounts([
+      ...cycle.beneficiaries.map(pubkey => ({ pubkey, isWritable: false, isSigner: false })),
+      ...records,
+    ])
+    .signers([cycle.creator])
+    .rpc();
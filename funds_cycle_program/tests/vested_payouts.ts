@@ -0,0 +1,49 @@
+// user-049: vested payouts
+import { describe, it } from "node:test";
+import assert from "assert";
+import {
+  claimVested,
+  createCycle,
+  expectError,
+  fetchVault,
+  payRound,
+  provider,
+  streamPdaFor,
+  withdraw,
+} from "./utils/cycle.ts";
+
+describe("vested payouts", () => {
+  it("streams the payout and closes the finished stream back to the recipient", async () => {
+    // A zero-day interval vests the whole payout at once
+    const cycle = await createCycle({ terms: { vestPayouts: true } });
+    await payRound(cycle);
+
+    await expectError(withdraw(cycle, 0), "MissingStreamAccount");
+
+    const stream = streamPdaFor(cycle.beneficiaries[0]);
+    await withdraw(cycle, 0, stream);
+    assert.strictEqual((await fetchVault(cycle)).vesting.toNumber(), 1_600);
+
+    const before = await provider.connection.getBalance(cycle.members[0].publicKey);
+    const streamRent = await provider.connection.getBalance(stream);
+    await claimVested(cycle, 0);
+
+    assert.strictEqual((await fetchVault(cycle)).vesting.toNumber(), 0);
+    assert.strictEqual(await provider.connection.getAccountInfo(stream), null);
+    assert.strictEqual(
+      await provider.connection.getBalance(cycle.members[0].publicKey),
+      before + 1_600 + streamRent
+    );
+  });
+
+  it("rejects a stream account when payouts do not vest", async () => {
+    const cycle = await createCycle();
+    await payRound(cycle);
+
+    await expectError(
+      withdraw(cycle, 0, streamPdaFor(cycle.beneficiaries[0])),
+      "UnexpectedStreamAccount"
+    );
+    assert.strictEqual(await provider.connection.getAccountInfo(streamPdaFor(cycle.beneficiaries[0])), null);
+  });
+});
@@ -0,0 +1,101 @@
+// user-034: punishment vote
+import { describe, it } from "node:test";
+import assert from "assert";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  Cycle,
+  createCycle,
+  expectError,
+  noConfigChanges,
+  payRound,
+  program,
+  sleep,
+} from "./utils/cycle.ts";
+
+const proposalPdaFor = (cycle: Cycle, i: number) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("punishment"), cycle.config.toBuffer(), cycle.beneficiaries[i].toBuffer()],
+    program.programId
+  )[0];
+
+const votePdaFor = (proposal: PublicKey, voter: PublicKey) =>
+  PublicKey.findProgramAddressSync([Buffer.from("vote"), proposal.toBuffer(), voter.toBuffer()], program.programId)[0];
+
+const propose = (cycle: Cycle, accused: number) =>
+  program.methods
+    .proposePunishment()
+    .accountsStrict({
+      admin: cycle.creator.publicKey,
+      config: cycle.config,
+      payer: cycle.creator.publicKey,
+      beneficiary: cycle.beneficiaries[accused],
+      proposal: proposalPdaFor(cycle, accused),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.creator])
+    .rpc();
+
+const vote = (cycle: Cycle, accused: number, i: number, approve: boolean) => {
+  const proposal = proposalPdaFor(cycle, accused);
+  return program.methods
+    .votePunishment(approve)
+    .accountsStrict({
+      voter: cycle.members[i].publicKey,
+      config: cycle.config,
+      voterBeneficiary: cycle.beneficiaries[i],
+      proposal,
+      voteRecord: votePdaFor(proposal, cycle.members[i].publicKey),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([cycle.members[i]])
+    .rpc();
+};
+
+const terms = { punishMode: { vote: {} }, voteWindowDays: 1, voteMajorityPercent: 50 };
+
+// Voting windows run in whole days, so resolution cannot be reached on a
+// local validator - only the guards around it are exercised here
+describe("punishment vote", () => {
+  it("vote mode needs a voting window", async () => {
+    const cycle = await createCycle({ enroll: 0 });
+    await expectError(
+      program.methods
+        .updateConfig({ ...noConfigChanges, punishMode: { vote: {} }, voteMajorityPercent: 50 } as any)
+        .accountsStrict({ admin: cycle.creator.publicKey, config: cycle.config })
+        .signers([cycle.creator])
+        .rpc(),
+      "VoteWindowNotSet"
+    );
+  });
+
+  it("tallies votes and holds resolution until the window closes", async () => {
+    const cycle = await createCycle({ members: 3, terms });
+    await payRound(cycle, [0, 1]);
+    await sleep(1_500);
+
+    await propose(cycle, 2);
+    await vote(cycle, 2, 0, true);
+    await vote(cycle, 2, 1, false);
+    await expectError(vote(cycle, 2, 2, false), "AccusedCannotVote");
+
+    const proposal = await program.account.punishmentProposal.fetch(proposalPdaFor(cycle, 2));
+    assert.strictEqual(proposal.votesFor, 1);
+    assert.strictEqual(proposal.votesAgainst, 1);
+
+    await expectError(
+      program.methods
+        .resolvePunishment()
+        .accountsStrict({
+          signer: cycle.creator.publicKey,
+          config: cycle.config,
+          beneficiary: cycle.beneficiaries[2],
+          proposal: proposalPdaFor(cycle, 2),
+          proposer: cycle.creator.publicKey,
+          vault: cycle.vault,
+        })
+        .signers([cycle.creator])
+        .rpc(),
+      "VoteWindowOpen"
+    );
+  });
+});